@@ -0,0 +1,126 @@
+use std::{convert::Infallible, fmt, str::FromStr};
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Number(i128),
+    Text(String),
+}
+
+impl Answer {
+    pub fn as_number(&self) -> Option<i128> {
+        match self {
+            Self::Number(n) => Some(*n),
+            Self::Text(_) => None,
+        }
+    }
+
+    pub fn as_text(&self) -> Option<&str> {
+        match self {
+            Self::Number(_) => None,
+            Self::Text(text) => Some(text),
+        }
+    }
+
+    pub fn is_multiline(&self) -> bool {
+        self.as_text().is_some_and(|text| text.contains('\n'))
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Number(n) => write!(f, "{}", n),
+            Self::Text(text) => write!(f, "{}", text),
+        }
+    }
+}
+
+/// Numbers are parsed as `Answer::Number`, anything else is kept as
+/// `Answer::Text` without its trailing line breaks. Leading whitespace is
+/// significant for rendered answers such as day 13's.
+impl FromStr for Answer {
+    type Err = Infallible;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        Ok(match text.trim().parse() {
+            Ok(n) => Self::Number(n),
+            Err(_) => Self::Text(text.trim_end_matches(['\n', '\r']).to_string()),
+        })
+    }
+}
+
+macro_rules! impl_number {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(n: $t) -> Self {
+                    Self::Number(n as i128)
+                }
+            }
+
+            impl PartialEq<$t> for Answer {
+                fn eq(&self, other: &$t) -> bool {
+                    self.as_number() == Some(*other as i128)
+                }
+            }
+        )*
+    };
+}
+
+impl_number!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+impl From<String> for Answer {
+    fn from(text: String) -> Self {
+        Self::Text(text)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(text: &str) -> Self {
+        Self::Text(text.to_string())
+    }
+}
+
+impl PartialEq<str> for Answer {
+    fn eq(&self, other: &str) -> bool {
+        self.as_text() == Some(other)
+    }
+}
+
+impl PartialEq<&str> for Answer {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_text() == Some(*other)
+    }
+}
+
+#[cfg(test)]
+mod answer_tests {
+    use super::Answer;
+
+    #[test]
+    fn conversions() {
+        assert_eq!(Answer::from(42usize), Answer::Number(42));
+        assert_eq!(Answer::from(-3isize), Answer::Number(-3));
+        assert_eq!(Answer::from(u64::MAX), Answer::Number(u64::MAX as i128));
+        assert_eq!(Answer::from("#.#"), Answer::Text("#.#".to_string()));
+    }
+
+    #[test]
+    fn comparisons() {
+        assert_eq!(Answer::from(42u32), 42u64);
+        assert_eq!(Answer::from(-1i64), -1isize);
+        assert_ne!(Answer::from("42"), 42);
+        assert_eq!(Answer::from("abc"), "abc");
+    }
+
+    #[test]
+    fn parse() {
+        assert_eq!("1924\n".parse(), Ok(Answer::Number(1924)));
+        assert_eq!("-7".parse(), Ok(Answer::Number(-7)));
+        assert_eq!(
+            " #..#\n#..#\n".parse(),
+            Ok(Answer::Text(" #..#\n#..#".to_string()))
+        );
+        assert_eq!(Answer::Number(5).to_string(), "5");
+    }
+}
@@ -0,0 +1,424 @@
+use std::fmt;
+
+use adventofcode::runner::{
+    bench::{BenchArgs, DEFAULT_RUNS},
+    report::Format,
+    scaffold::NewArgs,
+    verify::VerifyArgs,
+    RunArgs,
+};
+
+pub(crate) const USAGE: &str = "Usage:
+    adventofcode [run] [DAYS] [--year <YEAR>] [--part <1|2>] [--input <PATH|->]
+                     [--format <text|json|csv>] [--jobs <N>]
+    adventofcode bench [DAYS] [--year <YEAR>] [--part <1|2>] [--input <PATH|->] [--runs <N>]
+                       [--baseline <PATH>] [--save-baseline <PATH>]
+    adventofcode verify [DAYS] [--year <YEAR>] [--part <1|2>] [--input <PATH>] [--save]
+    adventofcode new <DAY> [--year <YEAR>] [--title <TITLE>]
+    adventofcode list [--year <YEAR>]
+    adventofcode help
+
+DAYS is a comma separated list of days or ranges (e.g. `5`, `1-10`, `1,3,20-25`),
+given either as is or after --day.
+When DAYS is omitted every day is run.
+--year selects the event the days belong to, the latest one by default.
+--input reads the puzzle input from PATH, or from stdin when PATH is `-`.
+It defaults to `inputs/<year>/<day>` and requires exactly one day.
+--format selects how results are printed; json and csv emit one record per
+part with its day, part, answer, duration and status.
+--jobs runs up to N parts at the same time (default 1); results are still
+printed in day order.
+
+bench times parsing, part 1 and part 2 separately over N runs (default 10).
+--save-baseline stores the median timings in PATH, --baseline compares
+against a previously saved file.
+
+verify checks the answers against `<input>.answers` (`inputs/<year>/<day>.answers`
+by default). --save records the current answer of every part that has none.
+
+new creates the module of a day from the repository root, registers it in its
+year (creating the year when needed) and adds an empty input and an empty
+example fixture, `examples/<year>/<day>/example.txt` and `example.expected`.";
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) enum Command {
+    Run(RunArgs),
+    Bench(BenchArgs),
+    Verify(VerifyArgs),
+    New(NewArgs),
+    /// Lists the days of a single year, or of every year when missing
+    List(Option<usize>),
+    Help,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct ArgsError(String);
+
+impl fmt::Display for ArgsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+fn parse_day(text: &str) -> Result<usize, ArgsError> {
+    text.trim()
+        .parse()
+        .map_err(|_| ArgsError(format!("'{}' is not a valid day", text)))
+}
+
+pub(crate) fn parse_days(text: &str) -> Result<Vec<usize>, ArgsError> {
+    let mut days = Vec::new();
+
+    for chunk in text.split(',') {
+        if let Some((from, to)) = chunk.split_once('-') {
+            let (from, to) = (parse_day(from)?, parse_day(to)?);
+            if from > to {
+                return Err(ArgsError(format!("'{}' is not a valid range", chunk)));
+            }
+            days.extend(from..=to);
+        } else {
+            days.push(parse_day(chunk)?);
+        }
+    }
+
+    Ok(days)
+}
+
+fn parse_year(text: &str) -> Result<usize, ArgsError> {
+    text.trim()
+        .parse()
+        .map_err(|_| ArgsError(format!("'{}' is not a valid year", text)))
+}
+
+fn parse_part(text: &str) -> Result<usize, ArgsError> {
+    match text {
+        "1" => Ok(1),
+        "2" => Ok(2),
+        _ => Err(ArgsError(format!("'{}' is not a valid part", text))),
+    }
+}
+
+fn next_value<I: Iterator<Item = String>>(args: &mut I, flag: &str) -> Result<String, ArgsError> {
+    args.next()
+        .ok_or_else(|| ArgsError(format!("Missing value for {}", flag)))
+}
+
+/// Parses the arguments shared by every command that runs days. Flags that
+/// are not recognised are handed to `extra`, which returns whether it
+/// consumed them.
+fn parse_run_with<I, F>(mut args: I, mut extra: F) -> Result<RunArgs, ArgsError>
+where
+    I: Iterator<Item = String>,
+    F: FnMut(&str, &mut I) -> Result<bool, ArgsError>,
+{
+    let mut res = RunArgs::default();
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--year" | "-y" => {
+                res.year = Some(parse_year(&next_value(&mut args, "--year")?)?);
+            }
+            "--part" | "-p" => {
+                res.part = Some(parse_part(&next_value(&mut args, "--part")?)?);
+            }
+            "--input" | "-i" => {
+                res.input = Some(next_value(&mut args, "--input")?);
+            }
+            "--day" | "-d" if res.days.is_none() => {
+                res.days = Some(parse_days(&next_value(&mut args, "--day")?)?);
+            }
+            _ if extra(&arg, &mut args)? => (),
+            _ if res.days.is_none() && !arg.starts_with("--") => {
+                res.days = Some(parse_days(&arg)?);
+            }
+            _ => return Err(ArgsError(format!("Unexpected argument '{}'", arg))),
+        }
+    }
+
+    if res.input.is_some() && !matches!(res.days.as_deref(), Some([_])) {
+        return Err(ArgsError("--input requires exactly one day".into()));
+    }
+
+    Ok(res)
+}
+
+fn parse_run<I: Iterator<Item = String>>(args: I) -> Result<RunArgs, ArgsError> {
+    let mut format = Format::default();
+    let mut jobs = 1;
+
+    let mut res = parse_run_with(args, |arg, args| {
+        match arg {
+            "--format" | "-f" => {
+                let value = next_value(args, "--format")?;
+                format = value
+                    .parse()
+                    .map_err(|_| ArgsError(format!("'{}' is not a valid format", value)))?;
+            }
+            "--jobs" | "-j" => {
+                let value = next_value(args, "--jobs")?;
+                jobs = match value.parse() {
+                    Ok(n) if n > 0 => n,
+                    _ => return Err(ArgsError(format!("'{}' is not a valid job count", value))),
+                };
+            }
+            _ => return Ok(false),
+        }
+        Ok(true)
+    })?;
+
+    res.format = format;
+    res.jobs = jobs;
+    Ok(res)
+}
+
+fn parse_bench<I: Iterator<Item = String>>(args: I) -> Result<BenchArgs, ArgsError> {
+    let mut runs = DEFAULT_RUNS;
+    let mut baseline = None;
+    let mut save_baseline = None;
+
+    let run = parse_run_with(args, |arg, args| {
+        match arg {
+            "--runs" | "-n" => {
+                let value = next_value(args, "--runs")?;
+                runs = match value.parse() {
+                    Ok(n) if n > 0 => n,
+                    _ => return Err(ArgsError(format!("'{}' is not a valid run count", value))),
+                };
+            }
+            "--baseline" => baseline = Some(next_value(args, "--baseline")?),
+            "--save-baseline" => save_baseline = Some(next_value(args, "--save-baseline")?),
+            _ => return Ok(false),
+        }
+        Ok(true)
+    })?;
+
+    Ok(BenchArgs {
+        run,
+        runs,
+        baseline,
+        save_baseline,
+    })
+}
+
+fn parse_verify<I: Iterator<Item = String>>(args: I) -> Result<VerifyArgs, ArgsError> {
+    let mut save = false;
+
+    let run = parse_run_with(args, |arg, _| match arg {
+        "--save" => {
+            save = true;
+            Ok(true)
+        }
+        _ => Ok(false),
+    })?;
+
+    Ok(VerifyArgs { run, save })
+}
+
+fn parse_new<I: Iterator<Item = String>>(mut args: I) -> Result<NewArgs, ArgsError> {
+    let mut year = None;
+    let mut day = None;
+    let mut title = None;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--year" | "-y" => year = Some(parse_year(&next_value(&mut args, "--year")?)?),
+            "--title" | "-t" => title = Some(next_value(&mut args, "--title")?),
+            _ if day.is_none() && !arg.starts_with("--") => day = Some(parse_day(&arg)?),
+            _ => return Err(ArgsError(format!("Unexpected argument '{}'", arg))),
+        }
+    }
+
+    let day = day.ok_or_else(|| ArgsError("Missing the day to create".into()))?;
+    Ok(NewArgs { year, day, title })
+}
+
+pub(crate) fn parse<I: Iterator<Item = String>>(args: I) -> Result<Command, ArgsError> {
+    let mut args = args.peekable();
+
+    match args.peek().map(String::as_str) {
+        Some("run") => {
+            args.next();
+            parse_run(args).map(Command::Run)
+        }
+        Some("bench") => {
+            args.next();
+            parse_bench(args).map(Command::Bench)
+        }
+        Some("verify") => {
+            args.next();
+            parse_verify(args).map(Command::Verify)
+        }
+        Some("new") => {
+            args.next();
+            parse_new(args).map(Command::New)
+        }
+        Some("list") => {
+            args.next();
+            match (args.next().as_deref(), args.next()) {
+                (None, _) => Ok(Command::List(None)),
+                (Some("--year" | "-y"), Some(year)) => match args.next() {
+                    None => Ok(Command::List(Some(parse_year(&year)?))),
+                    Some(arg) => Err(ArgsError(format!("Unexpected argument '{}'", arg))),
+                },
+                (Some(flag @ ("--year" | "-y")), None) => {
+                    Err(ArgsError(format!("Missing value for {}", flag)))
+                }
+                (Some(arg), _) => Err(ArgsError(format!("Unexpected argument '{}'", arg))),
+            }
+        }
+        Some("help" | "--help" | "-h") => Ok(Command::Help),
+        _ => parse_run(args).map(Command::Run),
+    }
+}
+
+#[cfg(test)]
+mod cli_tests {
+    use super::{
+        parse, parse_days, ArgsError, BenchArgs, Command, Format, NewArgs, RunArgs, VerifyArgs,
+        DEFAULT_RUNS,
+    };
+
+    fn args(text: &str) -> impl Iterator<Item = String> + '_ {
+        text.split_whitespace().map(ToString::to_string)
+    }
+
+    #[test]
+    fn days() {
+        assert_eq!(parse_days("5"), Ok(vec![5]));
+        assert_eq!(parse_days("1-3"), Ok(vec![1, 2, 3]));
+        assert_eq!(parse_days("1,3,20-22"), Ok(vec![1, 3, 20, 21, 22]));
+        assert!(parse_days("3-1").is_err());
+        assert!(parse_days("a").is_err());
+    }
+
+    #[test]
+    fn commands() {
+        assert_eq!(parse(args("")), Ok(Command::Run(RunArgs::default())));
+        assert_eq!(parse(args("list")), Ok(Command::List(None)));
+        assert_eq!(
+            parse(args("list --year 2021")),
+            Ok(Command::List(Some(2021)))
+        );
+        assert!(parse(args("list 2021")).is_err());
+        assert!(parse(args("list --year")).is_err());
+        assert_eq!(
+            parse(args("run 16 --part 2")),
+            Ok(Command::Run(RunArgs {
+                year: None,
+                days: Some(vec![16]),
+                part: Some(2),
+                input: None,
+                format: Format::Text,
+                jobs: 1,
+            }))
+        );
+        assert_eq!(
+            parse(args("5 --input -")),
+            Ok(Command::Run(RunArgs {
+                year: None,
+                days: Some(vec![5]),
+                part: None,
+                input: Some("-".into()),
+                format: Format::Text,
+                jobs: 1,
+            }))
+        );
+        assert_eq!(
+            parse(args("bench 19,22 --runs 3 --save-baseline base.txt")),
+            Ok(Command::Bench(BenchArgs {
+                run: RunArgs {
+                    year: None,
+                    days: Some(vec![19, 22]),
+                    part: None,
+                    input: None,
+                    format: Format::Text,
+                    jobs: 1,
+                },
+                runs: 3,
+                baseline: None,
+                save_baseline: Some("base.txt".into()),
+            }))
+        );
+        assert_eq!(
+            parse(args("bench")),
+            Ok(Command::Bench(BenchArgs {
+                run: RunArgs::default(),
+                runs: DEFAULT_RUNS,
+                baseline: None,
+                save_baseline: None,
+            }))
+        );
+        assert_eq!(
+            parse(args("run 1-3 --format json")),
+            Ok(Command::Run(RunArgs {
+                year: None,
+                days: Some(vec![1, 2, 3]),
+                part: None,
+                input: None,
+                format: Format::Json,
+                jobs: 1,
+            }))
+        );
+        assert_eq!(
+            parse(args("-j 4")),
+            Ok(Command::Run(RunArgs {
+                jobs: 4,
+                ..RunArgs::default()
+            }))
+        );
+        assert_eq!(
+            parse(args("run --year 2021 --day 5")),
+            Ok(Command::Run(RunArgs {
+                year: Some(2021),
+                days: Some(vec![5]),
+                ..RunArgs::default()
+            }))
+        );
+        assert_eq!(
+            parse(args("run 5 --day 6")),
+            Err(ArgsError("Unexpected argument '--day'".into()))
+        );
+        assert_eq!(
+            parse(args("verify 5 -y 2021")),
+            Ok(Command::Verify(VerifyArgs {
+                run: RunArgs {
+                    year: Some(2021),
+                    days: Some(vec![5]),
+                    ..RunArgs::default()
+                },
+                save: false,
+            }))
+        );
+        assert!(parse(args("run --year twenty")).is_err());
+        assert!(parse(args("run --jobs 0")).is_err());
+        assert!(parse(args("bench --jobs 2")).is_err());
+        assert!(parse(args("run --format xml")).is_err());
+        assert!(parse(args("bench --format csv")).is_err());
+        assert!(parse(args("bench --runs 0")).is_err());
+        assert_eq!(
+            parse(args("verify 1-2 --save")),
+            Ok(Command::Verify(VerifyArgs {
+                run: RunArgs {
+                    days: Some(vec![1, 2]),
+                    ..RunArgs::default()
+                },
+                save: true,
+            }))
+        );
+        assert_eq!(
+            parse(args("new 23 --year 2022 --title Amphipod")),
+            Ok(Command::New(NewArgs {
+                year: Some(2022),
+                day: 23,
+                title: Some("Amphipod".into()),
+            }))
+        );
+        assert!(parse(args("new")).is_err());
+        assert!(parse(args("new 1-3")).is_err());
+        assert!(parse(args("run --save")).is_err());
+        assert!(parse(args("run 1 --runs 3")).is_err());
+        assert!(parse(args("run 1-2 --input -")).is_err());
+        assert!(parse(args("run 1 --part 3")).is_err());
+        assert!(parse(args("run 1 2")).is_err());
+    }
+}
@@ -1,14 +0,0 @@
-const INPUT: &str = include_str!("../../inputs/24");
-
-pub(crate) fn solution1(_text: &str) -> usize {
-    todo!()
-}
-
-pub(crate) fn solution2(_text: &str) -> usize {
-    todo!()
-}
-
-pub fn solution() {
-    print!("Solution 1: {}", solution1(INPUT));
-    print!("Solution 2: {}", solution2(INPUT));
-}
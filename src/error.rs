@@ -0,0 +1,220 @@
+use std::{fmt, str::FromStr};
+
+use crate::utils::grid::Grid;
+
+/// Describes why an input could not be parsed and where: `position` is the
+/// 1-based `(line, column)`, missing when the input is well formed but has no
+/// solution. `day` is filled in by `Solution::part` once the error reaches the
+/// runner.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    pub day: Option<usize>,
+    pub position: Option<(usize, usize)>,
+    pub message: String,
+}
+
+pub type ParseResult<T> = Result<T, ParseError>;
+
+impl ParseError {
+    pub fn new(line: usize, column: usize, message: impl Into<String>) -> Self {
+        Self {
+            day: None,
+            position: Some((line, column)),
+            message: message.into(),
+        }
+    }
+
+    /// An input that parses but that the puzzle cannot be solved for, which
+    /// has no position to point at.
+    pub fn unsolvable(message: impl Into<String>) -> Self {
+        Self {
+            day: None,
+            position: None,
+            message: message.into(),
+        }
+    }
+
+    /// Points at `field`, which must be a sub-slice of `text`, the `line`-th
+    /// line of the input.
+    pub fn at(line: usize, text: &str, field: &str, message: impl Into<String>) -> Self {
+        Self::new(line, column(text, field), message)
+    }
+
+    pub fn in_day(self, day: usize) -> Self {
+        Self {
+            day: Some(day),
+            ..self
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let position = self
+            .position
+            .map(|(line, column)| format!("line {}, column {}", line, column));
+        let day = self.day.map(|day| format!("day {}", day));
+        match (day, position) {
+            (Some(day), Some(position)) => write!(f, "{}, {}: ", day, position)?,
+            (Some(prefix), None) | (None, Some(prefix)) => write!(f, "{}: ", prefix)?,
+            (None, None) => {}
+        }
+        write!(f, "{}", self.message)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// 1-based column of `field` within `text`, falling back to the first column
+/// when `field` is not a sub-slice of `text`.
+fn column(text: &str, field: &str) -> usize {
+    let start = text.as_ptr() as usize;
+    let offset = (field.as_ptr() as usize).wrapping_sub(start);
+    if offset
+        .checked_add(field.len())
+        .is_some_and(|end| end <= text.len())
+    {
+        text[..offset].chars().count() + 1
+    } else {
+        1
+    }
+}
+
+/// Parses `field`, a sub-slice of `text`, the `line`-th line of the input.
+pub fn parse_field<T: FromStr>(line: usize, text: &str, field: &str) -> ParseResult<T> {
+    field
+        .trim()
+        .parse()
+        .map_err(|_| ParseError::at(line, text, field, format!("invalid value '{}'", field)))
+}
+
+/// Drops the blank lines at the end of `text`, which editors and downloads
+/// tend to leave behind.
+pub fn trim_blank_lines(text: &str) -> &str {
+    let content = text.trim_end().len();
+    match text[content..].find('\n') {
+        _ if content == 0 => "",
+        Some(i) => &text[..content + i + 1],
+        None => text,
+    }
+}
+
+/// Parses every line of `text` with `f`, which is given the 1-based line
+/// number along with the line. Trailing blank lines are skipped.
+pub fn parse_lines<T, F>(text: &str, mut f: F) -> ParseResult<Vec<T>>
+where
+    F: FnMut(usize, &str) -> ParseResult<T>,
+{
+    trim_blank_lines(text)
+        .lines()
+        .enumerate()
+        .map(|(i, line)| f(i + 1, line))
+        .collect()
+}
+
+/// Parses every character of every line of `text` with `f`, which returns
+/// `None` for characters that are not valid.
+pub fn parse_chars<T, F>(text: &str, mut f: F) -> ParseResult<Vec<Vec<T>>>
+where
+    F: FnMut(char) -> Option<T>,
+{
+    parse_lines(text, |i, line| {
+        line.chars()
+            .enumerate()
+            .map(|(j, c)| {
+                f(c).ok_or_else(|| ParseError::new(i, j + 1, format!("invalid character '{}'", c)))
+            })
+            .collect()
+    })
+}
+
+/// Parses a rectangular grid of characters like [`parse_chars`], every line
+/// being as long as the first one.
+pub fn parse_grid<T, F>(text: &str, f: F) -> ParseResult<Grid<T>>
+where
+    F: FnMut(char) -> Option<T>,
+{
+    let rows = parse_chars(text, f)?;
+    let width = rows.first().map_or(0, Vec::len);
+    if let Some((i, row)) = rows.iter().enumerate().find(|(_, row)| row.len() != width) {
+        return Err(ParseError::new(
+            i + 1,
+            row.len().min(width) + 1,
+            format!("expected {} cells, found {}", width, row.len()),
+        ));
+    }
+
+    Ok(Grid::from_rows(rows).unwrap())
+}
+
+#[cfg(test)]
+mod error_tests {
+    use super::{parse_chars, parse_field, parse_grid, parse_lines, trim_blank_lines, ParseError};
+
+    #[test]
+    fn positions() {
+        let line = "forward x5";
+        assert_eq!(
+            parse_field::<usize>(3, line, &line[8..]),
+            Err(ParseError::new(3, 9, "invalid value 'x5'"))
+        );
+        assert_eq!(parse_field::<usize>(3, line, "12"), Ok(12));
+        assert_eq!(
+            ParseError::at(2, "é=x", &"é=x"[3..], "oops").position,
+            Some((2, 3))
+        );
+        assert_eq!(
+            ParseError::at(2, "abc", "other", "oops").position,
+            Some((2, 1))
+        );
+        assert_eq!(
+            ParseError::unsolvable("no path").in_day(15).to_string(),
+            "day 15: no path"
+        );
+        assert_eq!(ParseError::unsolvable("no path").to_string(), "no path");
+    }
+
+    #[test]
+    fn lines() {
+        assert_eq!(
+            parse_lines("1\n2\n", |i, line| parse_field::<u8>(i, line, line)),
+            Ok(vec![1, 2])
+        );
+        assert_eq!(
+            parse_lines("1\n-2\n", |i, line| parse_field::<u8>(i, line, line))
+                .map_err(|err| err.in_day(1).to_string()),
+            Err("day 1, line 2, column 1: invalid value '-2'".to_string())
+        );
+        assert_eq!(
+            parse_lines("1\n2\n\n \n", |i, line| parse_field::<u8>(i, line, line)),
+            Ok(vec![1, 2])
+        );
+        assert_eq!(
+            parse_lines("1\n\n2", |i, line| parse_field::<u8>(i, line, line)),
+            Err(ParseError::new(2, 1, "invalid value ''"))
+        );
+        assert_eq!(trim_blank_lines("a \r\n\n  \n"), "a \r\n");
+        assert_eq!(trim_blank_lines("a\n b"), "a\n b");
+        assert_eq!(trim_blank_lines("\n\n"), "");
+        assert_eq!(
+            parse_grid("12\n34\n\n", |c| c.to_digit(10)).map(|grid| grid.into_rows()),
+            Ok(vec![vec![1, 2], vec![3, 4]])
+        );
+        assert_eq!(
+            parse_chars("12\n34", |c| c.to_digit(10)),
+            Ok(vec![vec![1, 2], vec![3, 4]])
+        );
+        assert_eq!(
+            parse_chars("12\n3x", |c| c.to_digit(10)),
+            Err(ParseError::new(2, 2, "invalid character 'x'"))
+        );
+        assert_eq!(
+            parse_grid("12\n34", |c| c.to_digit(10)).map(|grid| grid.into_rows()),
+            Ok(vec![vec![1, 2], vec![3, 4]])
+        );
+        assert_eq!(
+            parse_grid("12\n3", |c| c.to_digit(10)),
+            Err(ParseError::new(2, 2, "expected 2 cells, found 1"))
+        );
+    }
+}
@@ -1,5 +1,3 @@
-#![allow(dead_code)]
-
 use bimap::BiBTreeMap;
 
 pub struct Graph<T: Ord> {
@@ -43,6 +41,12 @@ impl<'a, T: Ord> Iterator for Nodes<'a, T> {
     }
 }
 
+impl<T: Ord> Default for Graph<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl<T: Ord> Graph<T> {
     pub fn new() -> Self {
         Self {
@@ -115,11 +119,7 @@ impl<T: Ord> Graph<T> {
     }
 
     pub fn neighbors<'a>(&'a self, a: &T) -> Neighbors<'a, T> {
-        let it = if let Some(a) = self.map.get_by_left(a) {
-            Some(self.graph.neighbors(*a))
-        } else {
-            None
-        };
+        let it = self.map.get_by_left(a).map(|a| self.graph.neighbors(*a));
 
         Neighbors { it, graph: self }
     }
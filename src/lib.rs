@@ -0,0 +1,8 @@
+pub mod answer;
+pub mod error;
+pub mod graph;
+pub mod runner;
+pub mod solution;
+pub mod utils;
+pub mod window;
+pub mod years;
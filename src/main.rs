@@ -1,64 +1,47 @@
-pub mod days;
-pub(crate) mod graph;
-pub(crate) mod utils;
-pub(crate) mod window;
+use std::process::ExitCode;
 
-const DAYS: &'static [fn() -> ()] = &[
-    days::one::solution,
-    days::two::solution,
-    days::three::solution,
-    days::four::solution,
-    days::five::solution,
-    days::six::solution,
-    days::seven::solution,
-    days::eight::solution,
-    days::nine::solution,
-    days::ten::solution,
-    days::eleven::solution,
-    days::twelve::solution,
-    days::thirteen::solution,
-    days::fourteen::solution,
-    days::fifteen::solution,
-    days::sixteen::solution,
-    days::seventeen::solution,
-    days::eighteen::solution,
-    days::nineteen::solution,
-    days::twenty::solution,
-    days::twentyone::solution,
-    days::twentytwo::solution,
-    days::twentythree::solution,
-    days::twentyfour::solution,
-    days::twentyfive::solution,
-];
+use adventofcode::{
+    runner::{self, bench, isolate, scaffold, verify},
+    years::{self, YEARS},
+};
+use cli::Command;
 
-fn main() {
-    match std::env::args().len() {
-        1 => {
-            for day in 1..=DAYS.len() {
-                run(day);
+mod cli;
+
+fn main() -> ExitCode {
+    isolate::install_hook();
+
+    match cli::parse(std::env::args().skip(1)) {
+        Ok(Command::Run(args)) => runner::run(args),
+        Ok(Command::Bench(args)) => bench::run(args),
+        Ok(Command::Verify(args)) => verify::run(args),
+        Ok(Command::New(args)) => scaffold::run(args),
+        Ok(Command::List(year)) => {
+            let selected = match year {
+                Some(year) => match years::get(year) {
+                    Some(year) => std::slice::from_ref(year),
+                    None => {
+                        eprintln!("Year {} does not exist", year);
+                        return ExitCode::FAILURE;
+                    }
+                },
+                None => YEARS,
+            };
+            for year in selected {
+                println!("{}", year.year);
+                for solution in year.days {
+                    println!("  Day {}: {}", solution.day(), solution.title());
+                }
             }
+            ExitCode::SUCCESS
         }
-        2 => {
-            let day_string = std::env::args().nth(1).unwrap();
-            if let Ok(day) = day_string.parse::<usize>() {
-                run(day);
-            } else {
-                println!("'{}' is not a valid day", day_string);
-            }
+        Ok(Command::Help) => {
+            println!("{}", cli::USAGE);
+            ExitCode::SUCCESS
         }
-        _ => {
-            println!("Invalid arguments");
+        Err(err) => {
+            eprintln!("{}\n\n{}", err, cli::USAGE);
+            ExitCode::from(2)
         }
     }
 }
-
-fn run(day: usize) {
-    let idx = day - 1;
-    if !(0..DAYS.len()).contains(&idx) {
-        println!("Day {} does not exist", day);
-        return;
-    }
-
-    println!("Day {}:", day);
-    DAYS[idx]();
-}
@@ -0,0 +1,302 @@
+use std::{
+    collections::BTreeMap,
+    fmt, fs, io,
+    process::ExitCode,
+    time::{Duration, Instant},
+};
+
+use super::{isolate::isolate, read_input, report::Status, select, RunArgs};
+
+pub const DEFAULT_RUNS: usize = 10;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BenchArgs {
+    pub run: RunArgs,
+    pub runs: usize,
+    pub baseline: Option<String>,
+    pub save_baseline: Option<String>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Stage {
+    Parse,
+    Part1,
+    Part2,
+}
+
+impl Stage {
+    pub fn part(part: usize) -> Self {
+        match part {
+            1 => Self::Part1,
+            _ => Self::Part2,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Parse => "parse",
+            Self::Part1 => "part1",
+            Self::Part2 => "part2",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "parse" => Some(Self::Parse),
+            "part1" => Some(Self::Part1),
+            "part2" => Some(Self::Part2),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Stage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(self.name())
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &mut [Duration]) -> Option<Self> {
+        if samples.is_empty() {
+            return None;
+        }
+
+        samples.sort();
+        let len = samples.len();
+        let median = if len.is_multiple_of(2) {
+            (samples[len / 2 - 1] + samples[len / 2]) / 2
+        } else {
+            samples[len / 2]
+        };
+
+        Some(Self {
+            min: samples[0],
+            median,
+            mean: samples.iter().sum::<Duration>() / len as u32,
+        })
+    }
+}
+
+pub fn measure<F: FnMut()>(runs: usize, mut f: F) -> Stats {
+    let mut samples = (0..runs.max(1))
+        .map(|_| {
+            let start = Instant::now();
+            f();
+            start.elapsed()
+        })
+        .collect::<Vec<_>>();
+
+    Stats::from_samples(&mut samples).unwrap()
+}
+
+/// Median timings keyed by year, day and stage, stored one
+/// `<year> <day> <stage> <nanos>` entry per line.
+pub type Baseline = BTreeMap<(usize, usize, Stage), Duration>;
+
+fn invalid_data(line: &str) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidData,
+        format!("invalid baseline entry '{}'", line),
+    )
+}
+
+pub fn parse_baseline(text: &str) -> io::Result<Baseline> {
+    let mut baseline = Baseline::new();
+
+    for line in text.lines().map(str::trim).filter(|line| !line.is_empty()) {
+        let mut it = line.split_whitespace();
+        let year = it.next().and_then(|year| year.parse().ok());
+        let day = it.next().and_then(|day| day.parse().ok());
+        let stage = it.next().and_then(Stage::from_name);
+        let nanos = it.next().and_then(|nanos| nanos.parse().ok());
+
+        match (year, day, stage, nanos, it.next()) {
+            (Some(year), Some(day), Some(stage), Some(nanos), None) => {
+                baseline.insert((year, day, stage), Duration::from_nanos(nanos));
+            }
+            _ => return Err(invalid_data(line)),
+        }
+    }
+
+    Ok(baseline)
+}
+
+pub fn format_baseline(baseline: &Baseline) -> String {
+    baseline
+        .iter()
+        .map(|((year, day, stage), duration)| {
+            format!("{} {} {} {}\n", year, day, stage, duration.as_nanos())
+        })
+        .collect()
+}
+
+fn format_delta(current: Duration, previous: Option<&Duration>) -> String {
+    match previous {
+        Some(previous) if !previous.is_zero() => {
+            let delta = (current.as_secs_f64() / previous.as_secs_f64() - 1.0) * 100.0;
+            format!("  ({:+.1}% vs baseline)", delta)
+        }
+        _ => String::new(),
+    }
+}
+
+pub fn run(args: BenchArgs) -> ExitCode {
+    let (year, solutions) = match select(args.run.year, args.run.days) {
+        Some(selected) => selected,
+        None => return ExitCode::FAILURE,
+    };
+
+    let previous = match args.baseline.as_deref().map(fs::read_to_string) {
+        Some(Ok(text)) => match parse_baseline(&text) {
+            Ok(baseline) => baseline,
+            Err(err) => {
+                eprintln!("Cannot load baseline: {}", err);
+                return ExitCode::FAILURE;
+            }
+        },
+        Some(Err(err)) => {
+            eprintln!("Cannot load baseline: {}", err);
+            return ExitCode::FAILURE;
+        }
+        None => Baseline::new(),
+    };
+
+    let parts = match args.run.part {
+        Some(part) => part..=part,
+        None => 1..=2,
+    };
+
+    let mut status_code = ExitCode::SUCCESS;
+    let mut current = Baseline::new();
+    let mut total = Duration::ZERO;
+    // Only stages present in the baseline take part in the total comparison
+    let mut compared_total = Duration::ZERO;
+    let mut previous_total = Duration::ZERO;
+
+    for solution in solutions {
+        let day = solution.day();
+        let text = match read_input(year.year, day, args.run.input.as_deref()) {
+            Ok(text) => text,
+            Err(err) => {
+                eprintln!("Day {}: cannot read input: {}", day, err);
+                status_code = ExitCode::FAILURE;
+                continue;
+            }
+        };
+
+        // Every stage is run once under `isolate` first, so that a panicking
+        // part is reported instead of aborting the whole benchmark
+        let mut stages = Vec::new();
+        let mut failed = Vec::new();
+        let mut skipped = None;
+        match isolate(|| solution.parse(&text)) {
+            Ok(Ok(true)) => stages.push((
+                Stage::Parse,
+                measure(args.runs, || {
+                    std::hint::black_box(solution.parse(&text)).ok();
+                }),
+            )),
+            // Days parsing as they go have no parse stage to time
+            Ok(Ok(false)) => skipped = Some(Stage::Parse),
+            Ok(Err(err)) => failed.push((Stage::Parse, Status::Error(err.in_day(day).to_string()))),
+            Err(status) => failed.push((Stage::Parse, status)),
+        }
+        for part in parts.clone() {
+            match isolate(|| solution.part(part, &text).unwrap()) {
+                Ok(Err(err)) => failed.push((Stage::part(part), Status::Error(err.to_string()))),
+                Ok(Ok(_)) => stages.push((
+                    Stage::part(part),
+                    measure(args.runs, || {
+                        std::hint::black_box(solution.part(part, &text));
+                    }),
+                )),
+                Err(status) => failed.push((Stage::part(part), status)),
+            }
+        }
+
+        println!("Day {}: {}", day, solution.title());
+        for (stage, status) in failed {
+            println!("  {:<5}  {}", stage, status);
+            status_code = ExitCode::FAILURE;
+        }
+        if let Some(stage) = skipped {
+            println!("  {:<5}  n/a", stage);
+        }
+        for (stage, stats) in stages {
+            let before = previous.get(&(year.year, day, stage));
+            println!(
+                "  {:<5}  min {:>10.2?}  median {:>10.2?}  mean {:>10.2?}{}",
+                stage,
+                stats.min,
+                stats.median,
+                stats.mean,
+                format_delta(stats.median, before),
+            );
+
+            total += stats.median;
+            if let Some(before) = before {
+                compared_total += stats.median;
+                previous_total += *before;
+            }
+            current.insert((year.year, day, stage), stats.median);
+        }
+    }
+
+    println!(
+        "Total (median): {:.2?}{}",
+        total,
+        format_delta(compared_total, Some(&previous_total)),
+    );
+
+    if let Some(path) = args.save_baseline {
+        if let Err(err) = fs::write(&path, format_baseline(&current)) {
+            eprintln!("Cannot save baseline to {}: {}", path, err);
+            status_code = ExitCode::FAILURE;
+        }
+    }
+
+    status_code
+}
+
+#[cfg(test)]
+mod bench_tests {
+    use std::time::Duration;
+
+    use super::{format_baseline, parse_baseline, Baseline, Stage, Stats};
+
+    #[test]
+    fn stats() {
+        let mut samples = [5, 1, 3, 2].map(Duration::from_millis);
+        assert_eq!(
+            Stats::from_samples(&mut samples),
+            Some(Stats {
+                min: Duration::from_millis(1),
+                median: Duration::from_micros(2500),
+                mean: Duration::from_micros(2750),
+            })
+        );
+        assert_eq!(Stats::from_samples(&mut []), None);
+    }
+
+    #[test]
+    fn baseline() {
+        let mut baseline = Baseline::new();
+        baseline.insert((2021, 19, Stage::Parse), Duration::from_nanos(1200));
+        baseline.insert((2021, 19, Stage::Part2), Duration::from_secs(2));
+
+        let text = format_baseline(&baseline);
+        assert_eq!(text, "2021 19 parse 1200\n2021 19 part2 2000000000\n");
+        assert_eq!(parse_baseline(&text).unwrap(), baseline);
+        assert!(parse_baseline("2021 19 part3 12").is_err());
+        // Baselines saved without the year are not mistaken for another day
+        assert!(parse_baseline("19 part2 12").is_err());
+    }
+}
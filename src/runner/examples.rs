@@ -0,0 +1,130 @@
+use std::{
+    fmt, fs, io,
+    path::{Path, PathBuf},
+};
+
+use super::{
+    report::Status,
+    run_part,
+    verify::{parse_answers, Answers},
+};
+use crate::solution::Solution;
+
+pub const EXAMPLES_DIR: &str = "examples";
+pub const INPUT_EXTENSION: &str = "txt";
+pub const EXPECTED_EXTENSION: &str = "expected";
+
+/// A puzzle example with its expected answers.
+///
+/// Examples live in `examples/<year>/<day>/`, where every `<name>.txt` input
+/// comes with a `<name>.expected` file in the same format as the answers
+/// checked by `verify`. Parts missing from it are not checked.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Example {
+    pub name: String,
+    pub input: String,
+    pub expected: Answers,
+}
+
+pub fn examples_dir(root: &Path, year: usize, day: usize) -> PathBuf {
+    root.join(year.to_string()).join(day.to_string())
+}
+
+fn invalid_data(path: &Path, message: impl fmt::Display) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidData,
+        format!("{}: {}", path.display(), message),
+    )
+}
+
+/// Loads every example of a day, sorted by name. A day without examples has
+/// no directory at all.
+pub fn load(root: &Path, year: usize, day: usize) -> io::Result<Vec<Example>> {
+    let dir = examples_dir(root, year, day);
+    let entries = match fs::read_dir(&dir) {
+        Ok(entries) => entries,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(err) => return Err(err),
+    };
+
+    let mut examples = Vec::new();
+    for entry in entries {
+        let path = entry?.path();
+        if path.extension().and_then(|ext| ext.to_str()) != Some(INPUT_EXTENSION) {
+            continue;
+        }
+
+        let expected_path = path.with_extension(EXPECTED_EXTENSION);
+        let expected = match fs::read_to_string(&expected_path) {
+            Ok(text) => parse_answers(&text).map_err(|err| invalid_data(&expected_path, err))?,
+            Err(err) => return Err(invalid_data(&expected_path, err)),
+        };
+
+        examples.push(Example {
+            name: path.file_stem().unwrap().to_string_lossy().into_owned(),
+            input: fs::read_to_string(&path)?,
+            expected,
+        });
+    }
+
+    examples.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(examples)
+}
+
+/// Runs the parts of `example` that have an expected answer, returning a
+/// description of every mismatch.
+pub fn check(year: usize, solution: &dyn Solution, example: &Example) -> Vec<String> {
+    let mut failures = Vec::new();
+
+    for (&part, expected) in &example.expected {
+        let report = run_part(year, solution, part, &example.input);
+        let failure = match (&report.status, &report.answer) {
+            (Status::Ok, Some(actual)) if actual == expected => continue,
+            (Status::Ok, Some(actual)) => format!("expected {}, got {}", expected, actual),
+            (status, _) => status.to_string(),
+        };
+
+        failures.push(format!(
+            "Day {} part {} ({}): {}",
+            solution.day(),
+            part,
+            example.name,
+            failure
+        ));
+    }
+
+    failures
+}
+
+#[cfg(test)]
+mod examples_tests {
+    use std::path::Path;
+
+    use super::{check, load, EXAMPLES_DIR};
+    use crate::years::YEARS;
+
+    /// Runs every example fixture of every year.
+    #[test]
+    fn examples() {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR")).join(EXAMPLES_DIR);
+        let mut failures = Vec::new();
+        let mut checked = 0;
+
+        for year in YEARS {
+            for &solution in year.days {
+                let examples = load(&root, year.year, solution.day()).unwrap();
+                for example in examples {
+                    checked += example.expected.len();
+                    failures.extend(
+                        check(year.year, solution, &example)
+                            .into_iter()
+                            .map(|failure| format!("{}: {}", year.year, failure)),
+                    );
+                }
+            }
+        }
+
+        assert!(checked > 0);
+        assert!(failures.is_empty(), "\n{}", failures.join("\n"));
+    }
+}
@@ -0,0 +1,97 @@
+use std::{
+    any::Any,
+    cell::{Cell, RefCell},
+    panic::{self, AssertUnwindSafe},
+};
+
+use super::report::Status;
+
+thread_local! {
+    static ISOLATING: Cell<bool> = const { Cell::new(false) };
+    static LOCATION: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+/// Wraps the current panic hook: panics inside `isolate` are not printed, only
+/// where they happened is remembered so that `isolate` can report it alongside
+/// the failing part. Any other panic goes to the previous hook as usual.
+pub fn install_hook() {
+    let previous = panic::take_hook();
+    panic::set_hook(Box::new(move |info| {
+        if !ISOLATING.with(Cell::get) {
+            return previous(info);
+        }
+
+        let location = info
+            .location()
+            .map(|l| format!("{}:{}:{}", l.file(), l.line(), l.column()));
+        LOCATION.with(|last| *last.borrow_mut() = location);
+    }));
+}
+
+/// Marks the thread as isolating until dropped, even when unwinding.
+struct Isolating(bool);
+
+impl Isolating {
+    fn start() -> Self {
+        Self(ISOLATING.with(|isolating| isolating.replace(true)))
+    }
+}
+
+impl Drop for Isolating {
+    fn drop(&mut self) {
+        ISOLATING.with(|isolating| isolating.set(self.0));
+    }
+}
+
+fn payload_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "unknown panic".to_string()
+    }
+}
+
+/// Runs `f`, turning a panic into a failed `Status`. `todo!()` and
+/// `unimplemented!()` are reported as `Status::Unimplemented`.
+pub fn isolate<T, F: FnOnce() -> T>(f: F) -> Result<T, Status> {
+    LOCATION.with(|last| last.borrow_mut().take());
+
+    let isolating = Isolating::start();
+    let res = panic::catch_unwind(AssertUnwindSafe(f));
+    drop(isolating);
+
+    res.map_err(|payload| {
+        let message = payload_message(payload.as_ref());
+        if message.starts_with("not yet implemented") || message.starts_with("not implemented") {
+            return Status::Unimplemented;
+        }
+
+        match LOCATION.with(|last| last.borrow_mut().take()) {
+            Some(location) => Status::Panicked(format!("{} at {}", message, location)),
+            None => Status::Panicked(message),
+        }
+    })
+}
+
+#[cfg(test)]
+mod isolate_tests {
+    use super::{isolate, ISOLATING};
+    use crate::runner::report::Status;
+
+    #[test]
+    fn isolate_results() {
+        assert_eq!(isolate(|| 42), Ok(42));
+        assert_eq!(isolate(|| -> usize { todo!() }), Err(Status::Unimplemented));
+        assert_eq!(
+            isolate(|| -> usize { unimplemented!() }),
+            Err(Status::Unimplemented)
+        );
+        assert!(matches!(
+            isolate(|| -> usize { panic!("Invalid text") }),
+            Err(Status::Panicked(message)) if message.starts_with("Invalid text")
+        ));
+        assert!(!ISOLATING.with(|isolating| isolating.get()));
+    }
+}
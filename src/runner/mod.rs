@@ -0,0 +1,197 @@
+pub mod bench;
+pub mod examples;
+pub mod isolate;
+pub mod pool;
+pub mod report;
+pub mod scaffold;
+pub mod verify;
+
+use std::{io::Read, process::ExitCode, time::Instant};
+
+use crate::{
+    solution::Solution,
+    years::{self, Year},
+};
+use isolate::isolate;
+use report::{Format, PartReport, Reporter, Status};
+
+pub const INPUTS_DIR: &str = "inputs";
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RunArgs {
+    /// The latest year when missing
+    pub year: Option<usize>,
+    pub days: Option<Vec<usize>>,
+    pub part: Option<usize>,
+    pub input: Option<String>,
+    pub format: Format,
+    /// Number of parts run at the same time
+    pub jobs: usize,
+}
+
+impl Default for RunArgs {
+    fn default() -> Self {
+        Self {
+            year: None,
+            days: None,
+            part: None,
+            input: None,
+            format: Format::default(),
+            jobs: 1,
+        }
+    }
+}
+
+/// Inputs of each year live in their own directory, `inputs/<year>/<day>`.
+pub fn input_path(year: usize, day: usize) -> String {
+    format!("{}/{}/{}", INPUTS_DIR, year, day)
+}
+
+pub fn read_input(year: usize, day: usize, path: Option<&str>) -> std::io::Result<String> {
+    match path {
+        Some("-") => {
+            let mut text = String::new();
+            std::io::stdin().read_to_string(&mut text)?;
+            Ok(text)
+        }
+        Some(path) => std::fs::read_to_string(path),
+        None => std::fs::read_to_string(input_path(year, day)),
+    }
+}
+
+pub fn select(
+    year: Option<usize>,
+    days: Option<Vec<usize>>,
+) -> Option<(&'static Year, Vec<&'static dyn Solution>)> {
+    let year = match year {
+        Some(year) => match years::get(year) {
+            Some(year) => year,
+            None => {
+                eprintln!("Year {} does not exist", year);
+                return None;
+            }
+        },
+        None => years::latest(),
+    };
+
+    let days = match days {
+        Some(days) => days,
+        None => return Some((year, year.days.to_vec())),
+    };
+
+    match days
+        .iter()
+        .map(|&day| year.get(day).ok_or(day))
+        .collect::<Result<Vec<_>, _>>()
+    {
+        Ok(solutions) => Some((year, solutions)),
+        Err(day) => {
+            eprintln!("Day {} does not exist in {}", day, year.year);
+            None
+        }
+    }
+}
+
+pub fn run_part(year: usize, solution: &dyn Solution, part: usize, text: &str) -> PartReport {
+    let start = Instant::now();
+    let res = isolate(|| solution.part(part, text).unwrap());
+    let duration = start.elapsed();
+
+    let (answer, status) = match res {
+        Ok(Ok(answer)) => (Some(answer), Status::Ok),
+        Ok(Err(err)) => (None, Status::Error(err.to_string())),
+        Err(status) => (None, status),
+    };
+
+    PartReport {
+        year,
+        day: solution.day(),
+        title: solution.title(),
+        part,
+        answer,
+        duration,
+        status,
+    }
+}
+
+pub fn run(args: RunArgs) -> ExitCode {
+    let (year, solutions) = match select(args.year, args.days) {
+        Some(selected) => selected,
+        None => return ExitCode::FAILURE,
+    };
+
+    let parts = match args.part {
+        Some(part) => part..=part,
+        None => 1..=2,
+    };
+
+    let mut status = ExitCode::SUCCESS;
+    let mut inputs = Vec::new();
+    for solution in solutions {
+        let day = solution.day();
+        match read_input(year.year, day, args.input.as_deref()) {
+            Ok(text) => inputs.push((solution, text)),
+            Err(err) => {
+                eprintln!("Day {}: cannot read input: {}", day, err);
+                status = ExitCode::FAILURE;
+            }
+        }
+    }
+
+    // Every part is independent, so they are all scheduled on the pool and
+    // reported back in day order
+    let tasks = inputs
+        .iter()
+        .flat_map(|(solution, text)| parts.clone().map(move |part| (*solution, part, text)))
+        .collect::<Vec<_>>();
+
+    let mut failures = Vec::new();
+    let mut reporter = Reporter::new(std::io::stdout(), args.format);
+    let mut write_error = None;
+    pool::run_ordered(
+        &tasks,
+        args.jobs,
+        |&(solution, part, text)| run_part(year.year, solution, part, text),
+        |report| {
+            if write_error.is_none() {
+                write_error = reporter.report(&report).err();
+            }
+            if report.status != Status::Ok {
+                failures.push(report);
+            }
+        },
+    );
+
+    if let Some(err) = write_error {
+        eprintln!("Cannot write report: {}", err);
+        return ExitCode::FAILURE;
+    }
+
+    if let Err(err) = reporter.finish() {
+        eprintln!("Cannot write report: {}", err);
+        return ExitCode::FAILURE;
+    }
+
+    if !failures.is_empty() {
+        // Keep stdout parseable for the machine-readable formats
+        let summary = format_failures(&failures);
+        match args.format {
+            Format::Text => print!("{}", summary),
+            Format::Json | Format::Csv => eprint!("{}", summary),
+        }
+        status = ExitCode::FAILURE;
+    }
+
+    status
+}
+
+fn format_failures(failures: &[PartReport]) -> String {
+    let mut res = format!("\n{} part(s) failed:\n", failures.len());
+    for report in failures {
+        res.push_str(&format!(
+            "  Day {} part {}: {}\n",
+            report.day, report.part, report.status
+        ));
+    }
+    res
+}
@@ -0,0 +1,84 @@
+use std::{
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        mpsc,
+    },
+    thread,
+};
+
+/// Runs `f` on every task using up to `jobs` threads, handing the results to
+/// `emit` in task order as soon as all the previous ones are available.
+pub fn run_ordered<T, R, F, E>(tasks: &[T], jobs: usize, f: F, mut emit: E)
+where
+    T: Sync,
+    R: Send,
+    F: Fn(&T) -> R + Sync,
+    E: FnMut(R),
+{
+    let jobs = jobs.clamp(1, tasks.len().max(1));
+    if jobs == 1 {
+        tasks.iter().map(&f).for_each(emit);
+        return;
+    }
+
+    let next = AtomicUsize::new(0);
+    let (tx, rx) = mpsc::channel();
+
+    thread::scope(|scope| {
+        for _ in 0..jobs {
+            let tx = tx.clone();
+            let (next, f) = (&next, &f);
+            scope.spawn(move || loop {
+                let i = next.fetch_add(1, Ordering::Relaxed);
+                match tasks.get(i) {
+                    Some(task) => {
+                        if tx.send((i, f(task))).is_err() {
+                            break;
+                        }
+                    }
+                    None => break,
+                }
+            });
+        }
+        drop(tx);
+
+        let mut pending = (0..tasks.len()).map(|_| None).collect::<Vec<_>>();
+        let mut emitted = 0;
+        for (i, res) in rx {
+            pending[i] = Some(res);
+            while let Some(res) = pending.get_mut(emitted).and_then(Option::take) {
+                emit(res);
+                emitted += 1;
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod pool_tests {
+    use std::{thread, time::Duration};
+
+    use super::run_ordered;
+
+    #[test]
+    fn ordered() {
+        let tasks = (0..20).collect::<Vec<u64>>();
+        for jobs in [1, 4, 100] {
+            let mut res = Vec::new();
+            run_ordered(
+                &tasks,
+                jobs,
+                |&n| {
+                    thread::sleep(Duration::from_millis(20 - n));
+                    n * 2
+                },
+                |n| res.push(n),
+            );
+            assert_eq!(res, tasks.iter().map(|n| n * 2).collect::<Vec<_>>());
+        }
+
+        let mut res = Vec::new();
+        run_ordered(&[] as &[u64], 4, |&n| n, |n| res.push(n));
+        assert!(res.is_empty());
+    }
+}
@@ -0,0 +1,341 @@
+use std::{
+    fmt,
+    io::{self, Write},
+    str::FromStr,
+    time::Duration,
+};
+
+use crate::answer::Answer;
+
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum Format {
+    #[default]
+    Text,
+    Json,
+    Csv,
+}
+
+impl FromStr for Format {
+    type Err = ();
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        match text {
+            "text" => Ok(Self::Text),
+            "json" => Ok(Self::Json),
+            "csv" => Ok(Self::Csv),
+            _ => Err(()),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Status {
+    Ok,
+    /// The input could not be parsed
+    Error(String),
+    Panicked(String),
+    Unimplemented,
+}
+
+impl Status {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Ok => "ok",
+            Self::Error(_) => "error",
+            Self::Panicked(_) => "panicked",
+            Self::Unimplemented => "unimplemented",
+        }
+    }
+
+    pub fn message(&self) -> Option<&str> {
+        match self {
+            Self::Error(message) | Self::Panicked(message) => Some(message),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Error(message) => write!(f, "error: {}", message),
+            Self::Panicked(message) => write!(f, "panicked: {}", message),
+            _ => write!(f, "{}", self.name()),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct PartReport {
+    pub year: usize,
+    pub day: usize,
+    pub title: &'static str,
+    pub part: usize,
+    pub answer: Option<Answer>,
+    pub duration: Duration,
+    pub status: Status,
+}
+
+/// Writes part reports as they come in. JSON output is a single array, so it
+/// is only complete once `finish` has been called.
+pub struct Reporter<W: Write> {
+    out: W,
+    format: Format,
+    count: usize,
+    last_day: Option<usize>,
+}
+
+fn json_string(text: &str) -> String {
+    let mut res = String::with_capacity(text.len() + 2);
+    res.push('"');
+    for c in text.chars() {
+        match c {
+            '"' => res.push_str("\\\""),
+            '\\' => res.push_str("\\\\"),
+            '\n' => res.push_str("\\n"),
+            '\r' => res.push_str("\\r"),
+            '\t' => res.push_str("\\t"),
+            c if (c as u32) < 0x20 => res.push_str(&format!("\\u{:04x}", c as u32)),
+            c => res.push(c),
+        }
+    }
+    res.push('"');
+    res
+}
+
+/// Numbers are written as strings too, as most JSON readers cannot hold every
+/// `i128` exactly.
+fn json_answer(answer: Option<&Answer>) -> String {
+    match answer {
+        Some(Answer::Number(n)) => json_string(&n.to_string()),
+        Some(Answer::Text(text)) => json_string(text),
+        None => "null".to_string(),
+    }
+}
+
+fn csv_field(text: &str) -> String {
+    if text.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", text.replace('"', "\"\""))
+    } else {
+        text.to_string()
+    }
+}
+
+impl<W: Write> Reporter<W> {
+    pub fn new(out: W, format: Format) -> Self {
+        Self {
+            out,
+            format,
+            count: 0,
+            last_day: None,
+        }
+    }
+
+    pub fn report(&mut self, report: &PartReport) -> io::Result<()> {
+        match self.format {
+            Format::Text => self.text(report)?,
+            Format::Json => self.json(report)?,
+            Format::Csv => self.csv(report)?,
+        }
+        self.count += 1;
+        self.last_day = Some(report.day);
+        self.out.flush()
+    }
+
+    pub fn finish(mut self) -> io::Result<W> {
+        match self.format {
+            Format::Json if self.count == 0 => writeln!(self.out, "[]")?,
+            Format::Json => writeln!(self.out, "\n]")?,
+            Format::Csv if self.count == 0 => self.csv_header()?,
+            _ => (),
+        }
+        self.out.flush()?;
+        Ok(self.out)
+    }
+
+    fn text(&mut self, report: &PartReport) -> io::Result<()> {
+        if self.last_day != Some(report.day) {
+            writeln!(self.out, "Day {}: {}", report.day, report.title)?;
+        }
+
+        match &report.answer {
+            Some(answer) if answer.is_multiline() => writeln!(
+                self.out,
+                "Solution {} ({:?}):\n{}",
+                report.part, report.duration, answer
+            ),
+            Some(answer) => writeln!(
+                self.out,
+                "Solution {}: {} ({:?})",
+                report.part, answer, report.duration
+            ),
+            None => writeln!(
+                self.out,
+                "Solution {}: {} ({:?})",
+                report.part, report.status, report.duration
+            ),
+        }
+    }
+
+    fn json(&mut self, report: &PartReport) -> io::Result<()> {
+        write!(self.out, "{}", if self.count == 0 { "[\n" } else { ",\n" })?;
+        write!(
+            self.out,
+            "  {{\"year\": {}, \"day\": {}, \"part\": {}, \"answer\": {}, \"duration_ns\": {}, \"status\": {}",
+            report.year,
+            report.day,
+            report.part,
+            json_answer(report.answer.as_ref()),
+            report.duration.as_nanos(),
+            json_string(report.status.name()),
+        )?;
+        if let Some(message) = report.status.message() {
+            write!(self.out, ", \"message\": {}", json_string(message))?;
+        }
+        write!(self.out, "}}")
+    }
+
+    fn csv_header(&mut self) -> io::Result<()> {
+        writeln!(self.out, "year,day,part,answer,duration_ns,status,message")
+    }
+
+    fn csv(&mut self, report: &PartReport) -> io::Result<()> {
+        if self.count == 0 {
+            self.csv_header()?;
+        }
+
+        writeln!(
+            self.out,
+            "{},{},{},{},{},{},{}",
+            report.year,
+            report.day,
+            report.part,
+            csv_field(
+                &report
+                    .answer
+                    .as_ref()
+                    .map(ToString::to_string)
+                    .unwrap_or_default()
+            ),
+            report.duration.as_nanos(),
+            report.status.name(),
+            csv_field(report.status.message().unwrap_or_default()),
+        )
+    }
+}
+
+#[cfg(test)]
+mod report_tests {
+    use std::time::Duration;
+
+    use super::{Format, PartReport, Reporter, Status};
+    use crate::answer::Answer;
+
+    fn reports() -> Vec<PartReport> {
+        vec![
+            PartReport {
+                year: 2021,
+                day: 13,
+                title: "Transparent Origami",
+                part: 2,
+                answer: Some(Answer::from("#.\n.#")),
+                duration: Duration::from_nanos(1500),
+                status: Status::Ok,
+            },
+            PartReport {
+                year: 2021,
+                day: 23,
+                title: "Amphipod",
+                part: 1,
+                answer: None,
+                duration: Duration::from_nanos(10),
+                status: Status::Unimplemented,
+            },
+            PartReport {
+                year: 2021,
+                day: 23,
+                title: "Amphipod",
+                part: 2,
+                answer: Some(Answer::Number(i128::MAX)),
+                duration: Duration::from_nanos(30),
+                status: Status::Ok,
+            },
+            PartReport {
+                year: 2021,
+                day: 24,
+                title: "Arithmetic Logic Unit",
+                part: 1,
+                answer: None,
+                duration: Duration::from_nanos(20),
+                status: Status::Panicked("bad \"input\", line 1".into()),
+            },
+        ]
+    }
+
+    fn render(format: Format, reports: &[PartReport]) -> String {
+        let mut reporter = Reporter::new(Vec::new(), format);
+        for report in reports {
+            reporter.report(report).unwrap();
+        }
+        String::from_utf8(reporter.finish().unwrap()).unwrap()
+    }
+
+    #[test]
+    fn json() {
+        assert_eq!(render(Format::Json, &[]), "[]\n");
+        assert_eq!(
+            render(Format::Json, &reports()),
+            r##"[
+  {"year": 2021, "day": 13, "part": 2, "answer": "#.\n.#", "duration_ns": 1500, "status": "ok"},
+  {"year": 2021, "day": 23, "part": 1, "answer": null, "duration_ns": 10, "status": "unimplemented"},
+  {"year": 2021, "day": 23, "part": 2, "answer": "170141183460469231731687303715884105727", "duration_ns": 30, "status": "ok"},
+  {"year": 2021, "day": 24, "part": 1, "answer": null, "duration_ns": 20, "status": "panicked", "message": "bad \"input\", line 1"}
+]
+"##
+        );
+    }
+
+    #[test]
+    fn csv() {
+        assert_eq!(
+            render(Format::Csv, &[]),
+            "year,day,part,answer,duration_ns,status,message\n"
+        );
+        assert_eq!(
+            render(Format::Csv, &reports()),
+            "year,day,part,answer,duration_ns,status,message
+2021,13,2,\"#.\n.#\",1500,ok,
+2021,23,1,,10,unimplemented,
+2021,23,2,170141183460469231731687303715884105727,30,ok,
+2021,24,1,,20,panicked,\"bad \"\"input\"\", line 1\"
+"
+        );
+    }
+
+    #[test]
+    fn status() {
+        let status = Status::Error("day 3, line 2, column 3: invalid character '2'".into());
+        assert_eq!(status.name(), "error");
+        assert_eq!(
+            status.to_string(),
+            "error: day 3, line 2, column 3: invalid character '2'"
+        );
+    }
+
+    #[test]
+    fn text() {
+        assert_eq!(
+            render(Format::Text, &reports()),
+            "Day 13: Transparent Origami
+Solution 2 (1.5µs):
+#.
+.#
+Day 23: Amphipod
+Solution 1: unimplemented (10ns)
+Solution 2: 170141183460469231731687303715884105727 (30ns)
+Day 24: Arithmetic Logic Unit
+Solution 1: panicked: bad \"input\", line 1 (20ns)
+"
+        );
+    }
+}
@@ -0,0 +1,363 @@
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+    process::ExitCode,
+};
+
+use super::{
+    examples::{EXAMPLES_DIR, EXPECTED_EXTENSION, INPUT_EXTENSION},
+    input_path, INPUTS_DIR,
+};
+use crate::years;
+
+/// Where the year modules live, relative to the repository root
+pub const YEARS_DIR: &str = "src/years";
+
+const NAMES: [&str; 25] = [
+    "one",
+    "two",
+    "three",
+    "four",
+    "five",
+    "six",
+    "seven",
+    "eight",
+    "nine",
+    "ten",
+    "eleven",
+    "twelve",
+    "thirteen",
+    "fourteen",
+    "fifteen",
+    "sixteen",
+    "seventeen",
+    "eighteen",
+    "nineteen",
+    "twenty",
+    "twentyone",
+    "twentytwo",
+    "twentythree",
+    "twentyfour",
+    "twentyfive",
+];
+
+/// rustfmt keeps an array on a single line when it fits in this width
+const MAX_WIDTH: usize = 100;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NewArgs {
+    /// The latest year when missing
+    pub year: Option<usize>,
+    pub day: usize,
+    pub title: Option<String>,
+}
+
+/// The name of a day's module, e.g. `twentythree` for day 23.
+pub fn module_name(day: usize) -> Option<&'static str> {
+    NAMES.get(day.wrapping_sub(1)).copied()
+}
+
+fn module_day(name: &str) -> usize {
+    NAMES
+        .iter()
+        .position(|&n| n == name)
+        .map_or(usize::MAX, |i| i + 1)
+}
+
+/// The example fixture of a new day, `extension` telling the input from the
+/// expected answers.
+pub fn example_path(year: usize, day: usize, extension: &str) -> String {
+    format!("{}/{}/{}/example.{}", EXAMPLES_DIR, year, day, extension)
+}
+
+/// The source of a new day, with both parts left to implement. Its examples
+/// are checked by the fixture harness, so it has no tests of its own.
+pub fn day_module(day: usize, title: &str) -> String {
+    format!(
+        r#"use crate::error::ParseResult;
+use crate::solution::solution;
+
+pub fn solution1(_text: &str) -> ParseResult<usize> {{
+    todo!()
+}}
+
+pub fn solution2(_text: &str) -> ParseResult<usize> {{
+    todo!()
+}}
+
+solution!({day}, {title:?});
+"#
+    )
+}
+
+/// Formats an array of `items` the way rustfmt does: on one line when it
+/// fits, one item per line otherwise. A lone multi-line item, such as a
+/// struct literal, hugs the brackets.
+fn array(head: &str, items: &[String]) -> String {
+    let line = format!("{} = &[{}];", head, items.join(", "));
+    if (items.len() == 1 && line.contains('\n'))
+        || (line.len() <= MAX_WIDTH && !line.contains('\n'))
+    {
+        return line;
+    }
+
+    let mut res = format!("{} = &[\n", head);
+    for item in items {
+        for line in item.lines() {
+            res.push_str(&format!("    {}\n", line));
+        }
+        res.truncate(res.len() - 1);
+        res.push_str(",\n");
+    }
+    res.push_str("];");
+    res
+}
+
+/// Rewrites a registry module, replacing its `pub mod` declarations with
+/// `modules` and the array starting with `head` with `items`. Everything
+/// else in the module is kept as is.
+fn registry(text: &str, modules: &[String], head: &str, items: &[String]) -> String {
+    let mut res = String::new();
+    for module in modules {
+        res.push_str(&format!("pub mod {};\n", module));
+    }
+
+    let mut lines = text
+        .lines()
+        .filter(|line| !line.starts_with("pub mod "))
+        .skip_while(|line| line.is_empty());
+    while let Some(line) = lines.next() {
+        res.push('\n');
+        if line.starts_with(head) {
+            res.push_str(&array(head, items));
+            if !line.ends_with("];") {
+                lines.by_ref().find(|line| line.ends_with("];"));
+            }
+        } else {
+            res.push_str(line);
+        }
+    }
+    res.push('\n');
+    res
+}
+
+fn declared_modules(text: &str) -> Vec<String> {
+    text.lines()
+        .filter_map(|line| line.strip_prefix("pub mod "))
+        .filter_map(|line| line.strip_suffix(';'))
+        .map(str::to_string)
+        .collect()
+}
+
+/// Adds the day's module to the `DAYS` registry of a year module. An empty
+/// `text` creates the year module.
+pub fn register_day(text: &str, day: usize) -> String {
+    let text = match text {
+        "" => "use crate::solution::Solution;\n\npub const DAYS: &[&dyn Solution] = &[];\n",
+        text => text,
+    };
+
+    let mut modules = declared_modules(text);
+    modules.push(module_name(day).unwrap().to_string());
+    modules.sort();
+    modules.dedup();
+
+    let mut days = modules.clone();
+    days.sort_by_key(|name| module_day(name));
+    let items = days
+        .iter()
+        .map(|name| format!("&{}::Puzzle", name))
+        .collect::<Vec<_>>();
+
+    registry(text, &modules, "pub const DAYS: &[&dyn Solution]", &items)
+}
+
+/// Adds a year module to the `YEARS` registry.
+pub fn register_year(text: &str, year: usize) -> String {
+    let mut modules = declared_modules(text);
+    modules.push(format!("y{}", year));
+    modules.sort();
+    modules.dedup();
+
+    let items = modules
+        .iter()
+        .map(|name| {
+            format!(
+                "Year {{\n    year: {},\n    days: {}::DAYS,\n}}",
+                &name[1..],
+                name
+            )
+        })
+        .collect::<Vec<_>>();
+
+    registry(text, &modules, "pub const YEARS: &[Year]", &items)
+}
+
+/// Creates an empty file, leaving an existing one untouched.
+fn touch(path: &str) -> io::Result<bool> {
+    if Path::new(path).exists() {
+        return Ok(false);
+    }
+    if let Some(parent) = Path::new(path).parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(path, "")?;
+    Ok(true)
+}
+
+fn scaffold(year: usize, day: usize, title: &str) -> io::Result<Vec<String>> {
+    let name = module_name(day).unwrap();
+    let years_mod = PathBuf::from(YEARS_DIR).join("mod.rs");
+    if !years_mod.exists() {
+        return Err(io::Error::new(
+            io::ErrorKind::NotFound,
+            format!(
+                "{} not found, run this from the repository root",
+                years_mod.display()
+            ),
+        ));
+    }
+
+    let dir = PathBuf::from(YEARS_DIR).join(format!("y{}", year));
+    let module = dir.join(format!("{}.rs", name));
+    if module.exists() {
+        return Err(io::Error::new(
+            io::ErrorKind::AlreadyExists,
+            format!("{} already exists", module.display()),
+        ));
+    }
+
+    // The registries are only rewritten once every file they declare exists,
+    // so that a failure halfway never leaves them pointing at missing modules
+    let year_mod = dir.join("mod.rs");
+    let (days, years) = if year_mod.exists() {
+        (register_day(&fs::read_to_string(&year_mod)?, day), None)
+    } else {
+        let text = fs::read_to_string(&years_mod)?;
+        (register_day("", day), Some(register_year(&text, year)))
+    };
+
+    let mut created = Vec::new();
+    fs::create_dir_all(&dir)?;
+    fs::write(&module, day_module(day, title))?;
+    created.push(module.display().to_string());
+
+    for path in [
+        input_path(year, day),
+        example_path(year, day, INPUT_EXTENSION),
+        example_path(year, day, EXPECTED_EXTENSION),
+    ] {
+        if touch(&path)? {
+            created.push(path);
+        }
+    }
+
+    fs::write(&year_mod, days)?;
+    if let Some(years) = years {
+        created.push(year_mod.display().to_string());
+        fs::write(&years_mod, years)?;
+    }
+
+    Ok(created)
+}
+
+pub fn run(args: NewArgs) -> ExitCode {
+    let year = args.year.unwrap_or_else(|| years::latest().year);
+    if module_name(args.day).is_none() {
+        eprintln!("Day {} is not between 1 and {}", args.day, NAMES.len());
+        return ExitCode::FAILURE;
+    }
+
+    let title = args.title.unwrap_or_else(|| format!("Day {}", args.day));
+    match scaffold(year, args.day, &title) {
+        Ok(created) => {
+            for path in created {
+                println!("created {}", path);
+            }
+            println!(
+                "Paste the puzzle input in {}/{}/{}, the example in {} and its answers in {}",
+                INPUTS_DIR,
+                year,
+                args.day,
+                example_path(year, args.day, INPUT_EXTENSION),
+                example_path(year, args.day, EXPECTED_EXTENSION),
+            );
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            eprintln!("Cannot create day {} of {}: {}", args.day, year, err);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+#[cfg(test)]
+mod scaffold_tests {
+    use super::{day_module, module_name, register_day, register_year};
+
+    const DAYS: &str = include_str!("../years/y2021/mod.rs");
+    const YEARS: &str = include_str!("../years/mod.rs");
+
+    #[test]
+    fn names() {
+        assert_eq!(module_name(1), Some("one"));
+        assert_eq!(module_name(23), Some("twentythree"));
+        assert_eq!(module_name(0), None);
+        assert_eq!(module_name(26), None);
+    }
+
+    #[test]
+    fn module() {
+        let text = day_module(7, "The \"Treachery\" of Whales");
+        assert!(
+            text.starts_with("use crate::error::ParseResult;\nuse crate::solution::solution;\n")
+        );
+        assert!(text.ends_with("\nsolution!(7, \"The \\\"Treachery\\\" of Whales\");\n"));
+    }
+
+    #[test]
+    fn days() {
+        let without = DAYS
+            .lines()
+            .filter(|line| !line.contains("twelve"))
+            .map(|line| format!("{}\n", line))
+            .collect::<String>();
+
+        assert_eq!(register_day(&without, 12), DAYS);
+        assert_eq!(register_day(DAYS, 12), DAYS);
+        assert_eq!(
+            register_day("", 3),
+            "pub mod three;
+
+use crate::solution::Solution;
+
+pub const DAYS: &[&dyn Solution] = &[&three::Puzzle];
+"
+        );
+        assert!(register_day(&register_day("", 3), 1).contains("pub mod one;\npub mod three;\n"));
+        assert!(register_day(&register_day("", 3), 1)
+            .contains("pub const DAYS: &[&dyn Solution] = &[&one::Puzzle, &three::Puzzle];\n"));
+    }
+
+    #[test]
+    fn years() {
+        assert_eq!(register_year(YEARS, 2021), YEARS);
+
+        let text = register_year(YEARS, 2022);
+        assert!(text.starts_with("pub mod y2021;\npub mod y2022;\n\n"));
+        assert!(text.contains(
+            "pub const YEARS: &[Year] = &[
+    Year {
+        year: 2021,
+        days: y2021::DAYS,
+    },
+    Year {
+        year: 2022,
+        days: y2022::DAYS,
+    },
+];
+"
+        ));
+        assert!(text.contains("pub fn latest()"));
+    }
+}
@@ -0,0 +1,244 @@
+use std::{
+    collections::BTreeMap,
+    fs,
+    io::{self, IsTerminal},
+    process::ExitCode,
+};
+
+use super::{input_path, read_input, report::Status, run_part, select, RunArgs};
+use crate::answer::Answer;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VerifyArgs {
+    pub run: RunArgs,
+    pub save: bool,
+}
+
+const HEADER: &str = "== ";
+
+/// Expected answers of a single input, keyed by part.
+///
+/// They are stored next to the input in a `<input>.answers` file, where each
+/// answer follows a `== <part>` header line and runs until the next header.
+/// This keeps multi-line answers (e.g. day 13's rendered code) readable.
+pub type Answers = BTreeMap<usize, Answer>;
+
+pub fn parse_answers(text: &str) -> Result<Answers, String> {
+    let mut answers = Answers::new();
+    let mut current: Option<(usize, Vec<&str>)> = None;
+
+    for (i, line) in text.lines().enumerate() {
+        if let Some(part) = line.strip_prefix(HEADER) {
+            if let Some((part, lines)) = current.take() {
+                answers.insert(part, lines.join("\n").parse().unwrap());
+            }
+            let part = part
+                .trim()
+                .parse()
+                .map_err(|_| format!("line {}: invalid part '{}'", i + 1, part))?;
+            current = Some((part, Vec::new()));
+        } else if let Some((_, ref mut lines)) = current {
+            lines.push(line);
+        } else if !line.trim().is_empty() {
+            return Err(format!("line {}: answer without a part header", i + 1));
+        }
+    }
+
+    if let Some((part, lines)) = current {
+        answers.insert(part, lines.join("\n").parse().unwrap());
+    }
+
+    Ok(answers)
+}
+
+pub fn format_answers(answers: &Answers) -> String {
+    answers
+        .iter()
+        .map(|(part, answer)| format!("{}{}\n{}\n", HEADER, part, answer))
+        .collect()
+}
+
+pub fn answers_path(year: usize, day: usize, input: Option<&str>) -> Option<String> {
+    match input {
+        Some("-") => None,
+        Some(path) => Some(format!("{}.answers", path)),
+        None => Some(format!("{}.answers", input_path(year, day))),
+    }
+}
+
+fn load_answers(path: &str) -> io::Result<Answers> {
+    match fs::read_to_string(path) {
+        Ok(text) => parse_answers(&text).map_err(|err| {
+            io::Error::new(io::ErrorKind::InvalidData, format!("{}: {}", path, err))
+        }),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Answers::new()),
+        Err(err) => Err(err),
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum Verdict {
+    Pass,
+    Fail,
+    Missing,
+}
+
+impl Verdict {
+    fn label(&self, color: bool) -> String {
+        let (name, code) = match self {
+            Self::Pass => ("PASS", "32"),
+            Self::Fail => ("FAIL", "31"),
+            Self::Missing => ("MISSING", "33"),
+        };
+
+        if color {
+            format!("\x1b[{}m{:<7}\x1b[0m", code, name)
+        } else {
+            format!("{:<7}", name)
+        }
+    }
+}
+
+fn indent(answer: &Answer) -> String {
+    if answer.is_multiline() {
+        format!("\n{}", answer)
+    } else {
+        answer.to_string()
+    }
+}
+
+pub fn run(args: VerifyArgs) -> ExitCode {
+    let (year, solutions) = match select(args.run.year, args.run.days) {
+        Some(selected) => selected,
+        None => return ExitCode::FAILURE,
+    };
+
+    let parts = match args.run.part {
+        Some(part) => part..=part,
+        None => 1..=2,
+    };
+
+    let color = std::env::var_os("NO_COLOR").is_none() && io::stdout().is_terminal();
+    let (mut passed, mut failed, mut missing) = (0, 0, 0);
+    let mut status = ExitCode::SUCCESS;
+
+    for solution in solutions {
+        let day = solution.day();
+        let input = args.run.input.as_deref();
+        let path = match answers_path(year.year, day, input) {
+            Some(path) => path,
+            None => {
+                eprintln!("Day {}: answers cannot be verified for stdin input", day);
+                return ExitCode::FAILURE;
+            }
+        };
+
+        let (text, mut answers) = match (read_input(year.year, day, input), load_answers(&path)) {
+            (Ok(text), Ok(answers)) => (text, answers),
+            (Err(err), _) => {
+                eprintln!("Day {}: cannot read input: {}", day, err);
+                status = ExitCode::FAILURE;
+                continue;
+            }
+            (_, Err(err)) => {
+                eprintln!("Day {}: cannot read answers: {}", day, err);
+                status = ExitCode::FAILURE;
+                continue;
+            }
+        };
+
+        println!("Day {}: {}", day, solution.title());
+        let mut updated = false;
+        for part in parts.clone() {
+            let report = run_part(year.year, solution, part, &text);
+            let (verdict, details) = match (&report.status, &report.answer, answers.get(&part)) {
+                (Status::Ok, Some(actual), Some(expected)) if actual == expected => {
+                    (Verdict::Pass, indent(actual))
+                }
+                (Status::Ok, Some(actual), Some(expected)) => (
+                    Verdict::Fail,
+                    format!("expected {}, got {}", indent(expected), indent(actual)),
+                ),
+                (Status::Ok, Some(actual), None) => {
+                    if args.save {
+                        answers.insert(part, actual.clone());
+                        updated = true;
+                    }
+                    (Verdict::Missing, indent(actual))
+                }
+                (status, _, _) => (Verdict::Fail, status.to_string()),
+            };
+
+            println!(
+                "  part {}  {} {} ({:?})",
+                part,
+                verdict.label(color),
+                details,
+                report.duration
+            );
+
+            match verdict {
+                Verdict::Pass => passed += 1,
+                Verdict::Fail => {
+                    failed += 1;
+                    status = ExitCode::FAILURE;
+                }
+                Verdict::Missing => missing += 1,
+            }
+        }
+
+        if updated {
+            if let Err(err) = fs::write(&path, format_answers(&answers)) {
+                eprintln!("Day {}: cannot save answers to {}: {}", day, path, err);
+                status = ExitCode::FAILURE;
+            } else {
+                println!("  saved answers to {}", path);
+            }
+        }
+    }
+
+    println!(
+        "\n{} passed, {} failed, {} missing",
+        passed, failed, missing
+    );
+
+    status
+}
+
+#[cfg(test)]
+mod verify_tests {
+    use super::{answers_path, format_answers, parse_answers, Answers};
+    use crate::answer::Answer;
+
+    #[test]
+    fn answers() {
+        let text = "== 1
+618
+== 2
+ ##  #
+#  # #
+";
+        let mut answers = Answers::new();
+        answers.insert(1, Answer::Number(618));
+        answers.insert(2, Answer::from(" ##  #\n#  # #"));
+
+        assert_eq!(parse_answers(text), Ok(answers.clone()));
+        assert_eq!(format_answers(&answers), text);
+        assert_eq!(parse_answers(""), Ok(Answers::new()));
+        assert!(parse_answers("42\n").is_err());
+        assert!(parse_answers("== x\n42\n").is_err());
+    }
+
+    #[test]
+    fn paths() {
+        assert_eq!(
+            answers_path(2021, 5, None),
+            Some("inputs/2021/5.answers".into())
+        );
+        assert_eq!(
+            answers_path(2021, 5, Some("my/5")),
+            Some("my/5.answers".into())
+        );
+        assert_eq!(answers_path(2021, 5, Some("-")), None);
+    }
+}
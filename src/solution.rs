@@ -0,0 +1,77 @@
+pub use crate::answer::Answer;
+pub use crate::error::ParseError;
+
+/// A day's puzzle. Solutions are shared between the threads of a parallel
+/// run, so they must be `Sync`.
+pub trait Solution: Sync {
+    fn day(&self) -> usize;
+
+    fn title(&self) -> &'static str;
+
+    /// Runs only the input parsing step, so that it can be timed on its own.
+    /// Returns `false` when the day has no separate parsing step.
+    fn parse(&self, _text: &str) -> Result<bool, ParseError> {
+        Ok(false)
+    }
+
+    fn part1(&self, text: &str) -> Result<Answer, ParseError>;
+
+    fn part2(&self, text: &str) -> Result<Answer, ParseError>;
+
+    /// Runs the given part, tagging a parse error with the day.
+    fn part(&self, part: usize, text: &str) -> Option<Result<Answer, ParseError>> {
+        let res = match part {
+            1 => self.part1(text),
+            2 => self.part2(text),
+            _ => return None,
+        };
+
+        Some(res.map_err(|err| err.in_day(self.day())))
+    }
+}
+
+/// Declares a day's `Puzzle` and implements [`Solution`] for it with the
+/// module's `solution1` and `solution2` functions. The optional third
+/// argument is the function parsing the input, timed on its own.
+///
+/// ```ignore
+/// solution!(23, "Amphipod", parse);
+/// ```
+macro_rules! solution {
+    ($day:expr, $title:expr $(, $parse:expr)? $(,)?) => {
+        pub struct Puzzle;
+
+        impl $crate::solution::Solution for Puzzle {
+            fn day(&self) -> usize {
+                $day
+            }
+
+            fn title(&self) -> &'static str {
+                $title
+            }
+
+            $(
+                fn parse(&self, text: &str) -> Result<bool, $crate::solution::ParseError> {
+                    ::std::hint::black_box($parse(text)?);
+                    Ok(true)
+                }
+            )?
+
+            fn part1(
+                &self,
+                text: &str,
+            ) -> Result<$crate::solution::Answer, $crate::solution::ParseError> {
+                solution1(text).map($crate::solution::Answer::from)
+            }
+
+            fn part2(
+                &self,
+                text: &str,
+            ) -> Result<$crate::solution::Answer, $crate::solution::ParseError> {
+                solution2(text).map($crate::solution::Answer::from)
+            }
+        }
+    };
+}
+
+pub(crate) use solution;
@@ -0,0 +1,322 @@
+use std::{
+    ops::{Index, IndexMut},
+    slice,
+};
+
+use super::matrix::{Cells, CellsMut, Coord, Matrix};
+
+/// A rectangular grid stored row by row in a single vector. Like a
+/// [`Matrix`], it is addressed by `(row, column)`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// A grid of `height` rows of `width` cells, in the same order as
+    /// [`size`](Self::size), every cell being `value`.
+    pub fn new(height: usize, width: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    /// A grid of `width` columns made of `cells`, row by row. `None` when the
+    /// last row would be incomplete.
+    pub fn from_vec(width: usize, cells: Vec<T>) -> Option<Self> {
+        match width {
+            0 if cells.is_empty() => Some(Self {
+                width,
+                height: 0,
+                cells,
+            }),
+            0 => None,
+            _ if !cells.len().is_multiple_of(width) => None,
+            _ => Some(Self {
+                width,
+                height: cells.len() / width,
+                cells,
+            }),
+        }
+    }
+
+    /// `None` when the rows have different lengths.
+    pub fn from_rows(rows: Matrix<T>) -> Option<Self> {
+        let width = rows.first().map_or(0, Vec::len);
+        if rows.iter().any(|row| row.len() != width) {
+            return None;
+        }
+
+        Some(Self {
+            width,
+            height: rows.len(),
+            cells: rows.into_iter().flatten().collect(),
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// `(height, width)`, in the same order as a [`Coord`].
+    pub fn size(&self) -> (usize, usize) {
+        (self.height, self.width)
+    }
+
+    pub fn contains(&self, (row, column): Coord) -> bool {
+        row < self.height && column < self.width
+    }
+
+    pub fn get(&self, pos: Coord) -> Option<&T> {
+        if self.contains(pos) {
+            Some(&self.cells[pos.0 * self.width + pos.1])
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, pos: Coord) -> Option<&mut T> {
+        if self.contains(pos) {
+            Some(&mut self.cells[pos.0 * self.width + pos.1])
+        } else {
+            None
+        }
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    pub fn row_mut(&mut self, row: usize) -> &mut [T] {
+        &mut self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[T]> + ExactSizeIterator {
+        (0..self.height).map(|row| self.row(row))
+    }
+
+    /// The cells of `column` from top to bottom, `None` when it is out of
+    /// bounds.
+    pub fn column(
+        &self,
+        column: usize,
+    ) -> Option<impl DoubleEndedIterator<Item = &T> + ExactSizeIterator> {
+        if column < self.width {
+            Some((0..self.height).map(move |row| &self.cells[row * self.width + column]))
+        } else {
+            None
+        }
+    }
+
+    pub fn columns(
+        &self,
+    ) -> impl DoubleEndedIterator<Item = impl DoubleEndedIterator<Item = &T>> + ExactSizeIterator
+    {
+        (0..self.width).map(move |column| {
+            (0..self.height).map(move |row| &self.cells[row * self.width + column])
+        })
+    }
+
+    /// The cells row by row.
+    pub fn iter(&self) -> slice::Iter<'_, T> {
+        self.cells.iter()
+    }
+
+    pub fn iter_mut(&mut self) -> slice::IterMut<'_, T> {
+        self.cells.iter_mut()
+    }
+
+    pub fn map<U, F: FnMut(&T) -> U>(&self, f: F) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    /// Swaps rows and columns.
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            width: self.height,
+            height: self.width,
+            cells: self.columns().flatten().cloned().collect(),
+        }
+    }
+
+    pub fn into_rows(self) -> Matrix<T> {
+        if self.width == 0 {
+            return (0..self.height).map(|_| Vec::new()).collect();
+        }
+        let mut cells = self.cells.into_iter();
+        (0..self.height)
+            .map(|_| cells.by_ref().take(self.width).collect())
+            .collect()
+    }
+}
+
+impl<T> Cells for Grid<T> {
+    type Cell = T;
+
+    fn height(&self) -> usize {
+        self.height
+    }
+
+    fn row_width(&self, _row: usize) -> usize {
+        self.width
+    }
+
+    fn cell(&self, pos: Coord) -> Option<&T> {
+        self.get(pos)
+    }
+}
+
+impl<T> CellsMut for Grid<T> {
+    fn rows_mut(&mut self) -> Vec<&mut [T]> {
+        if self.width == 0 {
+            return (0..self.height).map(|_| &mut [][..]).collect();
+        }
+        self.cells.chunks_exact_mut(self.width).collect()
+    }
+}
+
+impl<T> Index<Coord> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Coord) -> &Self::Output {
+        if let Some(val) = self.get(pos) {
+            val
+        } else {
+            panic!(
+                "index out of bounds: the size is {:?} but the index is {:?}",
+                (self.height, self.width),
+                pos,
+            )
+        }
+    }
+}
+
+impl<T> IndexMut<Coord> for Grid<T> {
+    fn index_mut(&mut self, pos: Coord) -> &mut Self::Output {
+        let size = (self.height, self.width);
+        if let Some(val) = self.get_mut(pos) {
+            val
+        } else {
+            panic!(
+                "index out of bounds: the size is {:?} but the index is {:?}",
+                size, pos,
+            )
+        }
+    }
+}
+
+impl<'a, T> IntoIterator for &'a Grid<T> {
+    type Item = &'a T;
+    type IntoIter = slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut Grid<T> {
+    type Item = &'a mut T;
+    type IntoIter = slice::IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+#[cfg(test)]
+mod grid_tests {
+    use super::Grid;
+    use crate::utils::matrix::{cardinal_coords, dijkstra, enum_iter, navigate};
+
+    fn grid() -> Grid<u32> {
+        Grid::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap()
+    }
+
+    #[test]
+    fn layout() {
+        let grid = grid();
+        assert_eq!(grid.size(), (2, 3));
+        assert_eq!(grid[(1, 0)], 4);
+        assert_eq!(grid.get((0, 3)), None);
+        assert_eq!(grid.get((2, 0)), None);
+        assert_eq!(grid.rows().collect::<Vec<_>>(), [[1, 2, 3], [4, 5, 6]]);
+        assert_eq!(
+            grid.column(1)
+                .map(|column| column.copied().collect::<Vec<_>>()),
+            Some(vec![2, 5])
+        );
+        assert!(grid.column(3).is_none());
+        assert_eq!(grid.columns().len(), 3);
+        assert_eq!(
+            Grid::new(2, 3, 0).into_rows(),
+            [[0, 0, 0], [0, 0, 0]].map(Vec::from)
+        );
+
+        let transposed = grid.transpose();
+        assert_eq!(transposed.size(), (3, 2));
+        assert_eq!(
+            transposed.clone().into_rows(),
+            [[1, 4], [2, 5], [3, 6]].map(Vec::from)
+        );
+        assert_eq!(transposed.transpose(), grid);
+        assert!(grid.map(|&v| v % 2 == 0)[(0, 1)]);
+
+        assert_eq!(Grid::from_rows(vec![vec![1, 2], vec![3]]), None);
+        assert_eq!(Grid::from_vec(2, vec![1, 2, 3]), None);
+        assert_eq!(Grid::from_vec(3, vec![1, 2, 3, 4, 5, 6]), Some(grid));
+        assert_eq!(
+            Grid::<u8>::from_rows(vec![vec![], vec![]])
+                .unwrap()
+                .into_rows(),
+            [[], []]
+        );
+    }
+
+    #[test]
+    fn helpers() {
+        let mut grid = grid();
+        grid[(0, 1)] = 20;
+
+        assert_eq!(
+            enum_iter(&grid).map(|(pos, _)| pos).collect::<Vec<_>>(),
+            [(0, 0), (0, 1), (0, 2), (1, 0), (1, 1), (1, 2)]
+        );
+        assert_eq!(
+            dijkstra(&grid, (0, 0), (0, 2), cardinal_coords),
+            Some((vec![(0, 0), (1, 0), (1, 1), (1, 2), (0, 2)], 18))
+        );
+
+        let reached = navigate(
+            &grid,
+            cardinal_coords,
+            (0, 0),
+            vec![(0, 0)],
+            |mut reached, _, (pos, &value)| {
+                let ok = value < 5 && !reached.contains(&pos);
+                if ok {
+                    reached.push(pos);
+                }
+                (ok, reached)
+            },
+        );
+        assert_eq!(reached, [(0, 0), (1, 0)]);
+    }
+}
@@ -1,86 +1,241 @@
 use std::{
+    array,
     borrow::{Borrow, BorrowMut},
-    vec::IntoIter,
+    collections::{btree_map::Entry, BTreeMap, BTreeSet, VecDeque},
+    iter::Flatten,
+    mem,
 };
 
+use num_traits::Zero;
+
 pub type Matrix<T> = Vec<Vec<T>>;
 pub type Coord = (usize, usize);
+/// The cells listed by a navigator with at most `N` of them, kept on the stack
+/// since navigators run for every visited cell.
+pub type Neighbors<const N: usize> = Flatten<array::IntoIter<Option<Coord>, N>>;
+
+/// Cells addressed by `(row, column)`, which the helpers of this module work
+/// on. Rows may have different lengths.
+pub trait Cells {
+    type Cell;
+
+    fn height(&self) -> usize;
+
+    fn row_width(&self, row: usize) -> usize;
 
-#[derive(Copy, Clone, Debug)]
-pub struct MatrixEnumeratedIterator<'a, T> {
-    matrix: &'a Vec<Vec<T>>,
-    pos: Coord,
+    fn cell(&self, pos: Coord) -> Option<&Self::Cell>;
 }
 
-impl<'a, T> MatrixEnumeratedIterator<'a, T> {
-    pub fn new(matrix: &'a Vec<Vec<T>>) -> Self {
+impl<T> Cells for Matrix<T> {
+    type Cell = T;
+
+    fn height(&self) -> usize {
+        self.len()
+    }
+
+    fn row_width(&self, row: usize) -> usize {
+        self[row].len()
+    }
+
+    fn cell(&self, pos: Coord) -> Option<&T> {
+        self.get(pos.0).and_then(|row| row.get(pos.1))
+    }
+}
+
+/// Cells that can be changed in place, row by row.
+pub trait CellsMut: Cells {
+    fn rows_mut(&mut self) -> Vec<&mut [Self::Cell]>;
+}
+
+impl<T> CellsMut for Matrix<T> {
+    fn rows_mut(&mut self) -> Vec<&mut [T]> {
+        self.iter_mut().map(Vec::as_mut_slice).collect()
+    }
+}
+
+/// Iterator over the cells of a matrix along with their coordinates, row by
+/// row. Empty rows are skipped.
+#[derive(Debug)]
+pub struct MatrixEnumeratedIterator<'a, M: ?Sized> {
+    matrix: &'a M,
+    /// The next cell from the front
+    front: Coord,
+    /// Right after the next cell from the back, in the same row
+    back: Coord,
+    len: usize,
+}
+
+impl<M: ?Sized> Clone for MatrixEnumeratedIterator<'_, M> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<M: ?Sized> Copy for MatrixEnumeratedIterator<'_, M> {}
+
+impl<'a, M: Cells + ?Sized> MatrixEnumeratedIterator<'a, M> {
+    pub fn new(matrix: &'a M) -> Self {
         Self {
             matrix,
-            pos: (0, 0),
+            front: (0, 0),
+            back: (matrix.height(), 0),
+            len: (0..matrix.height()).map(|row| matrix.row_width(row)).sum(),
         }
     }
 }
 
-impl<'a, T> Iterator for MatrixEnumeratedIterator<'a, T> {
-    type Item = (Coord, &'a T);
+impl<'a, M: Cells + ?Sized> Iterator for MatrixEnumeratedIterator<'a, M> {
+    type Item = (Coord, &'a M::Cell);
 
     fn next(&mut self) -> Option<<Self as Iterator>::Item> {
-        while self.pos.0 < self.matrix.len() {
-            if self.pos.1 < self.matrix[self.pos.0].len() {
-                let res = (self.pos, &self.matrix[self.pos.0][self.pos.1]);
-
-                self.pos.1 += 1;
-                if self.pos.1 >= self.matrix[self.pos.0].len() {
-                    self.pos.0 += 1;
-                    self.pos.1 = 0;
-                }
+        if self.len == 0 {
+            return None;
+        }
 
-                return Some(res);
-            }
-            todo!()
+        while self.front.1 >= self.matrix.row_width(self.front.0) {
+            self.front = (self.front.0 + 1, 0);
         }
+        let pos = self.front;
+        self.front.1 += 1;
+        self.len -= 1;
 
-        None
+        self.matrix.cell(pos).map(|value| (pos, value))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
     }
 }
 
-pub fn enum_iter<'a, T>(matrix: &'a Matrix<T>) -> MatrixEnumeratedIterator<'a, T> {
+impl<M: Cells + ?Sized> DoubleEndedIterator for MatrixEnumeratedIterator<'_, M> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+
+        while self.back.1 == 0 {
+            self.back.0 -= 1;
+            self.back.1 = self.matrix.row_width(self.back.0);
+        }
+        self.back.1 -= 1;
+        let pos = self.back;
+        self.len -= 1;
+
+        self.matrix.cell(pos).map(|value| (pos, value))
+    }
+}
+
+impl<M: Cells + ?Sized> ExactSizeIterator for MatrixEnumeratedIterator<'_, M> {}
+
+pub fn enum_iter<M: Cells + ?Sized>(matrix: &M) -> MatrixEnumeratedIterator<'_, M> {
     MatrixEnumeratedIterator::new(matrix)
 }
 
-pub trait Navigator<T, I: Iterator<Item = Coord>> {
-    fn navigate(&mut self, matrix: &Matrix<T>, coord: &Coord) -> I;
+/// Mutable counterpart of [`MatrixEnumeratedIterator`].
+#[derive(Debug)]
+pub struct MatrixEnumeratedIterMut<'a, T> {
+    /// Every row left, as its index, the column of its first cell left and
+    /// the cells left
+    rows: VecDeque<(usize, usize, &'a mut [T])>,
+    len: usize,
+}
+
+impl<'a, T> MatrixEnumeratedIterMut<'a, T> {
+    pub fn new<M: CellsMut<Cell = T> + ?Sized>(matrix: &'a mut M) -> Self {
+        let rows = matrix
+            .rows_mut()
+            .into_iter()
+            .enumerate()
+            .filter(|(_, row)| !row.is_empty())
+            .map(|(i, row)| (i, 0, row))
+            .collect::<VecDeque<_>>();
+
+        Self {
+            len: rows.iter().map(|(_, _, row)| row.len()).sum(),
+            rows,
+        }
+    }
+}
+
+impl<'a, T> Iterator for MatrixEnumeratedIterMut<'a, T> {
+    type Item = (Coord, &'a mut T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (row, column, cells) = self.rows.front_mut()?;
+        let (value, rest) = mem::take(cells).split_first_mut()?;
+        let pos = (*row, *column);
+
+        *column += 1;
+        *cells = rest;
+        if cells.is_empty() {
+            self.rows.pop_front();
+        }
+        self.len -= 1;
+
+        Some((pos, value))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<T> DoubleEndedIterator for MatrixEnumeratedIterMut<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let (row, column, cells) = self.rows.back_mut()?;
+        let (value, rest) = mem::take(cells).split_last_mut()?;
+        let pos = (*row, *column + rest.len());
+
+        *cells = rest;
+        if cells.is_empty() {
+            self.rows.pop_back();
+        }
+        self.len -= 1;
+
+        Some((pos, value))
+    }
+}
+
+impl<T> ExactSizeIterator for MatrixEnumeratedIterMut<'_, T> {}
+
+pub fn enum_iter_mut<M: CellsMut + ?Sized>(matrix: &mut M) -> MatrixEnumeratedIterMut<'_, M::Cell> {
+    MatrixEnumeratedIterMut::new(matrix)
 }
 
-impl<I, T, F> Navigator<T, I> for F
+pub trait Navigator<M: ?Sized, I: Iterator<Item = Coord>> {
+    fn navigate(&mut self, matrix: &M, coord: &Coord) -> I;
+}
+
+impl<I, M, F> Navigator<M, I> for F
 where
     I: Iterator<Item = Coord>,
-    F: Fn(&Matrix<T>, &Coord) -> I,
+    M: ?Sized,
+    F: Fn(&M, &Coord) -> I,
 {
-    fn navigate(&mut self, matrix: &Matrix<T>, coord: &Coord) -> I {
+    fn navigate(&mut self, matrix: &M, coord: &Coord) -> I {
         self(matrix, coord)
     }
 }
 
 #[derive(Debug)]
-pub struct IndexesIterator<'a, T, I: Iterator<Item = Coord>> {
-    matrix: &'a Vec<Vec<T>>,
+pub struct IndexesIterator<'a, M: ?Sized, I: Iterator<Item = Coord>> {
+    matrix: &'a M,
     indexes: I,
 }
 
-impl<'a, T, I: Iterator<Item = Coord>> IndexesIterator<'a, T, I> {
-    pub fn new(matrix: &'a Vec<Vec<T>>, indexes: I) -> Self {
+impl<'a, M: ?Sized, I: Iterator<Item = Coord>> IndexesIterator<'a, M, I> {
+    pub fn new(matrix: &'a M, indexes: I) -> Self {
         Self { matrix, indexes }
     }
 }
 
-impl<'a, T, I: Iterator<Item = Coord>> Iterator for IndexesIterator<'a, T, I> {
-    type Item = (Coord, &'a T);
+impl<'a, M: Cells + ?Sized, I: Iterator<Item = Coord>> Iterator for IndexesIterator<'a, M, I> {
+    type Item = (Coord, &'a M::Cell);
 
     fn next(&mut self) -> Option<<Self as Iterator>::Item> {
-        while let Some((i, j)) = self.indexes.next() {
-            if let Some(v) = self.matrix.get(i).and_then(|v| v.get(j)) {
-                return Some(((i, j), v));
+        for pos in self.indexes.by_ref() {
+            if let Some(v) = self.matrix.cell(pos) {
+                return Some((pos, v));
             }
         }
 
@@ -88,62 +243,126 @@ impl<'a, T, I: Iterator<Item = Coord>> Iterator for IndexesIterator<'a, T, I> {
     }
 }
 
-pub fn enum_navigate<'a, T, I, N, B>(
-    matrix: &'a Matrix<T>,
+pub fn enum_navigate<'a, M, I, N, B>(
+    matrix: &'a M,
     coord: &Coord,
     mut navigator: B,
-) -> IndexesIterator<'a, T, I>
+) -> IndexesIterator<'a, M, I>
 where
+    M: Cells + ?Sized,
     I: Iterator<Item = Coord>,
-    N: Navigator<T, I>,
+    N: Navigator<M, I>,
     B: BorrowMut<N>,
 {
     IndexesIterator::new(matrix, navigator.borrow_mut().navigate(matrix, coord))
 }
 
-pub fn cardinal_coords<'a, T>(matrix: &'a Matrix<T>, pos: &Coord) -> IntoIter<Coord> {
-    let mut idxs = Vec::new();
+const CARDINAL: [(isize, isize); 4] = [(-1, 0), (0, -1), (0, 1), (1, 0)];
+const KING: [(isize, isize); 8] = [
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+    (0, -1),
+    (0, 1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+];
+const SQUARE: [(isize, isize); 9] = [
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+    (0, -1),
+    (0, 0),
+    (0, 1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+];
+const RIGHT_DOWN: [(isize, isize); 2] = [(0, 1), (1, 0)];
 
-    if matrix.get(pos.0).and_then(|v| v.get(pos.1)).is_none() {
-        return idxs.into_iter();
-    }
+/// The cells at `offsets` from `pos` that are in the matrix, none at all when
+/// `pos` itself is not.
+fn offset_coords<M: Cells + ?Sized, const N: usize>(
+    matrix: &M,
+    pos: &Coord,
+    offsets: &[(isize, isize); N],
+) -> Neighbors<N> {
+    let inside = matrix.cell(*pos).is_some();
+    offsets
+        .map(|(row, column)| {
+            let next = (
+                pos.0.checked_add_signed(row)?,
+                pos.1.checked_add_signed(column)?,
+            );
+            (inside && matrix.cell(next).is_some()).then_some(next)
+        })
+        .into_iter()
+        .flatten()
+}
 
-    if pos.0 > 0 && pos.1 < matrix[pos.0 - 1].len() {
-        idxs.push((pos.0 - 1, pos.1));
-    }
+/// The cells above, left, right and below.
+pub fn cardinal_coords<M: Cells + ?Sized>(matrix: &M, pos: &Coord) -> Neighbors<4> {
+    offset_coords(matrix, pos, &CARDINAL)
+}
 
-    if pos.1 > 0 {
-        idxs.push((pos.0, pos.1 - 1));
-    }
+/// The eight cells around, diagonals included, row by row.
+pub fn king_coords<M: Cells + ?Sized>(matrix: &M, pos: &Coord) -> Neighbors<8> {
+    offset_coords(matrix, pos, &KING)
+}
 
-    if pos.1 + 1 < matrix[pos.0].len() {
-        idxs.push((pos.0, pos.1 + 1));
-    }
+/// The 3×3 square centered on `pos`, the center included, row by row.
+pub fn square_coords<M: Cells + ?Sized>(matrix: &M, pos: &Coord) -> Neighbors<9> {
+    offset_coords(matrix, pos, &SQUARE)
+}
+
+/// The cells on the right and below, so that paths only go forward.
+pub fn right_down_coords<M: Cells + ?Sized>(matrix: &M, pos: &Coord) -> Neighbors<2> {
+    offset_coords(matrix, pos, &RIGHT_DOWN)
+}
 
-    if pos.0 + 1 < matrix.len() && pos.1 < matrix[pos.0 + 1].len() {
-        idxs.push((pos.0 + 1, pos.1));
+/// The cardinal cells of a matrix whose edges wrap around: leaving a row on
+/// the right enters it back on the left, and leaving the last row enters the
+/// first one. When the matrix is too small for four distinct neighbours, each
+/// cell is listed once and `pos` itself is left out.
+pub fn toroidal_coords<M: Cells + ?Sized>(matrix: &M, pos: &Coord) -> Neighbors<4> {
+    let mut coords = [None; CARDINAL.len()];
+    if matrix.cell(*pos).is_none() {
+        return coords.into_iter().flatten();
     }
 
-    idxs.into_iter()
+    let height = matrix.height() as isize;
+    let width = matrix.row_width(pos.0) as isize;
+    for (i, &(row, column)) in CARDINAL.iter().enumerate() {
+        let next = (
+            (pos.0 as isize + row).rem_euclid(height) as usize,
+            (pos.1 as isize + column).rem_euclid(width) as usize,
+        );
+        if next != *pos && !coords.contains(&Some(next)) && matrix.cell(next).is_some() {
+            coords[i] = Some(next);
+        }
+    }
+    coords.into_iter().flatten()
 }
 
-pub fn navigate<'a, T, I, N, B, F, S, C>(
-    matrix: &'a Matrix<T>,
+pub fn navigate<'a, M, I, N, B, F, S, C>(
+    matrix: &'a M,
     mut navigator: B,
     pos: C,
     mut state: S,
     mut reducer: F,
 ) -> S
 where
+    M: Cells + ?Sized,
     I: Iterator<Item = Coord>,
-    N: Navigator<T, I>,
+    N: Navigator<M, I>,
     B: BorrowMut<N>,
-    F: FnMut(S, (Coord, &'a T), (Coord, &'a T)) -> (bool, S),
+    F: FnMut(S, (Coord, &'a M::Cell), (Coord, &'a M::Cell)) -> (bool, S),
     C: Borrow<Coord>,
 {
-    let mut stack: Vec<(Coord, &'a T)> = Vec::new();
+    let mut stack: Vec<(Coord, &'a M::Cell)> = Vec::new();
     let pos = (pos.borrow().0, pos.borrow().1);
-    if let Some(value) = matrix.get(pos.0).and_then(|line| line.get(pos.1)) {
+    if let Some(value) = matrix.cell(pos) {
         stack.push((pos, value));
     }
 
@@ -160,29 +379,548 @@ where
     state
 }
 
-pub fn dijkstra<T, C1, C2, I, N, B>(
-    matrix: &Matrix<T>,
+/// Manhattan distance, the fewest cardinal moves from `a` to `b`. As an
+/// [`astar`] heuristic it never overestimates when every cell costs at least 1.
+pub fn manhattan(a: &Coord, b: &Coord) -> usize {
+    a.0.abs_diff(b.0) + a.1.abs_diff(b.1)
+}
+
+/// Chebyshev distance, the fewest king moves from `a` to `b`, diagonals
+/// included.
+pub fn chebyshev(a: &Coord, b: &Coord) -> usize {
+    a.0.abs_diff(b.0).max(a.1.abs_diff(b.1))
+}
+
+/// Successors in a search starting from all of `starts` at once, `None`
+/// standing for a virtual cell right before every one of them.
+fn successors<'a, M, I, N, C, F>(
+    matrix: &'a M,
+    navigator: &mut N,
+    starts: &[Coord],
+    cost: &mut F,
+    pos: &Option<Coord>,
+) -> Vec<(Option<Coord>, C)>
+where
+    M: Cells + ?Sized,
+    I: Iterator<Item = Coord>,
+    N: Navigator<M, I>,
+    C: Zero,
+    F: FnMut((Coord, &'a M::Cell), (Coord, &'a M::Cell)) -> C,
+{
+    let Some(pos) = pos else {
+        return starts
+            .iter()
+            .filter(|&&start| matrix.cell(start).is_some())
+            .map(|&start| (Some(start), C::zero()))
+            .collect();
+    };
+
+    let value = matrix.cell(*pos).unwrap();
+    enum_navigate::<_, I, N, _>(matrix, pos, navigator)
+        .map(|(next, v)| (Some(next), cost((*pos, value), (next, v))))
+        .collect()
+}
+
+/// Drops the virtual cell the search started from.
+fn real_path<C>((path, cost): (Vec<Option<Coord>>, C)) -> (Vec<Coord>, C) {
+    (path.into_iter().flatten().collect(), cost)
+}
+
+/// The cheapest path from any of `starts` to any of `ends`, `cost` giving
+/// the cost of moving from a cell to the next one.
+pub fn dijkstra_by<'a, M, I, N, B, C, F>(
+    matrix: &'a M,
+    starts: &[Coord],
+    ends: &[Coord],
+    mut navigator: B,
+    mut cost: F,
+) -> Option<(Vec<Coord>, C)>
+where
+    M: Cells + ?Sized,
+    I: Iterator<Item = Coord>,
+    N: Navigator<M, I>,
+    B: BorrowMut<N>,
+    C: Zero + Ord + Copy,
+    F: FnMut((Coord, &'a M::Cell), (Coord, &'a M::Cell)) -> C,
+{
+    pathfinding::directed::dijkstra::dijkstra(
+        &None,
+        |pos| successors(matrix, navigator.borrow_mut(), starts, &mut cost, pos),
+        |pos| pos.is_some_and(|pos| ends.contains(&pos)),
+    )
+    .map(real_path)
+}
+
+/// The cheapest path from `start` to `end`, moving to a cell costing its
+/// value.
+pub fn dijkstra<M, C1, C2, I, N, B>(
+    matrix: &M,
     start: C1,
     end: C2,
+    navigator: B,
+) -> Option<(Vec<Coord>, M::Cell)>
+where
+    M: Cells + ?Sized,
+    M::Cell: Copy + Ord + Zero,
+    C1: Borrow<Coord>,
+    C2: Borrow<Coord>,
+    I: Iterator<Item = Coord>,
+    N: Navigator<M, I>,
+    B: BorrowMut<N>,
+{
+    dijkstra_by::<_, I, N, _, _, _>(
+        matrix,
+        &[*start.borrow()],
+        &[*end.borrow()],
+        navigator,
+        |_, (_, &v)| v,
+    )
+}
+
+/// Like [`dijkstra_by`], guided by `heuristic`, which must never
+/// overestimate the cost left from a cell to the closest end.
+pub fn astar_by<'a, M, I, N, B, C, F, H>(
+    matrix: &'a M,
+    starts: &[Coord],
+    ends: &[Coord],
     mut navigator: B,
-) -> Option<(Vec<Coord>, T)>
+    mut cost: F,
+    mut heuristic: H,
+) -> Option<(Vec<Coord>, C)>
 where
-    T: Eq + std::hash::Hash + Copy + Clone + Ord + pathfinding::num_traits::Zero,
+    M: Cells + ?Sized,
+    I: Iterator<Item = Coord>,
+    N: Navigator<M, I>,
+    B: BorrowMut<N>,
+    C: Zero + Ord + Copy,
+    F: FnMut((Coord, &'a M::Cell), (Coord, &'a M::Cell)) -> C,
+    H: FnMut(&Coord) -> C,
+{
+    pathfinding::directed::astar::astar(
+        &None,
+        |pos| successors(matrix, navigator.borrow_mut(), starts, &mut cost, pos),
+        |pos| pos.as_ref().map_or_else(C::zero, &mut heuristic),
+        |pos| pos.is_some_and(|pos| ends.contains(&pos)),
+    )
+    .map(real_path)
+}
+
+/// Like [`dijkstra`], guided by `heuristic`, which must never overestimate
+/// the cost left from a cell to `end` for the path to be the cheapest one:
+/// [`manhattan`] fits cardinal moves and [`chebyshev`] king moves, as long as
+/// every cell costs at least 1. Cells that may cost 0 leave no better
+/// heuristic than 0.
+pub fn astar<M, C1, C2, I, N, B, H>(
+    matrix: &M,
+    start: C1,
+    end: C2,
+    navigator: B,
+    heuristic: H,
+) -> Option<(Vec<Coord>, M::Cell)>
+where
+    M: Cells + ?Sized,
+    M::Cell: Copy + Ord + Zero,
     C1: Borrow<Coord>,
     C2: Borrow<Coord>,
     I: Iterator<Item = Coord>,
-    N: Navigator<T, I>,
+    N: Navigator<M, I>,
     B: BorrowMut<N>,
+    H: FnMut(&Coord) -> M::Cell,
 {
-    let start = start.borrow();
-    let end = end.borrow();
+    astar_by::<_, I, N, _, _, _, _>(
+        matrix,
+        &[*start.borrow()],
+        &[*end.borrow()],
+        navigator,
+        |_, (_, &v)| v,
+        heuristic,
+    )
+}
 
-    pathfinding::directed::dijkstra::dijkstra(
-        start,
+/// The number of moves needed to reach every reachable cell from the closest
+/// of `starts`.
+pub fn bfs<M, I, N, B>(matrix: &M, starts: &[Coord], mut navigator: B) -> BTreeMap<Coord, usize>
+where
+    M: Cells + ?Sized,
+    I: Iterator<Item = Coord>,
+    N: Navigator<M, I>,
+    B: BorrowMut<N>,
+{
+    let mut distances = BTreeMap::new();
+    let mut queue = VecDeque::new();
+    for &start in starts {
+        if matrix.cell(start).is_some() && !distances.contains_key(&start) {
+            distances.insert(start, 0);
+            queue.push_back(start);
+        }
+    }
+
+    while let Some(pos) = queue.pop_front() {
+        let distance = distances[&pos] + 1;
+        for (next, _) in enum_navigate::<_, I, N, _>(matrix, &pos, navigator.borrow_mut()) {
+            if let Entry::Vacant(entry) = distances.entry(next) {
+                entry.insert(distance);
+                queue.push_back(next);
+            }
+        }
+    }
+
+    distances
+}
+
+/// A path with the fewest moves from any of `starts` to any of `ends`.
+pub fn bfs_path<M, I, N, B>(
+    matrix: &M,
+    starts: &[Coord],
+    ends: &[Coord],
+    mut navigator: B,
+) -> Option<Vec<Coord>>
+where
+    M: Cells + ?Sized,
+    I: Iterator<Item = Coord>,
+    N: Navigator<M, I>,
+    B: BorrowMut<N>,
+{
+    pathfinding::directed::bfs::bfs(
+        &None,
         |pos| {
-            enum_navigate::<_, I, N, _>(&matrix, pos, navigator.borrow_mut())
-                .map(|(pos, v)| (pos, v.clone()))
+            successors(matrix, navigator.borrow_mut(), starts, &mut |_, _| 0, pos)
+                .into_iter()
+                .map(|(next, _)| next)
         },
-        |pos| end == pos,
+        |pos| pos.is_some_and(|pos| ends.contains(&pos)),
     )
+    .map(|path| path.into_iter().flatten().collect())
+}
+
+/// The region of `start`: every cell reachable from it through cells that
+/// match `predicate`. Empty when `start` itself does not match.
+pub fn flood_fill<'a, M, I, N, B, P>(
+    matrix: &'a M,
+    start: Coord,
+    mut navigator: B,
+    mut predicate: P,
+) -> BTreeSet<Coord>
+where
+    M: Cells + ?Sized,
+    I: Iterator<Item = Coord>,
+    N: Navigator<M, I>,
+    B: BorrowMut<N>,
+    P: FnMut((Coord, &'a M::Cell)) -> bool,
+{
+    let mut region = BTreeSet::new();
+    let mut stack = Vec::new();
+    if let Some(value) = matrix.cell(start) {
+        if predicate((start, value)) {
+            region.insert(start);
+            stack.push(start);
+        }
+    }
+
+    while let Some(pos) = stack.pop() {
+        for (next, value) in enum_navigate::<_, I, N, _>(matrix, &pos, navigator.borrow_mut()) {
+            if !region.contains(&next) && predicate((next, value)) {
+                region.insert(next);
+                stack.push(next);
+            }
+        }
+    }
+
+    region
+}
+
+/// A region found by [`components`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Component {
+    pub id: usize,
+    pub size: usize,
+    /// The top left and bottom right corners of the bounding box, included
+    pub bounds: (Coord, Coord),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Components {
+    /// The component id of every cell, `None` when it does not match
+    pub labels: Matrix<Option<usize>>,
+    /// Indexed by id, in the order their first cell shows up row by row
+    pub components: Vec<Component>,
+}
+
+/// Splits the cells matching `predicate` into connected regions. The
+/// navigator should be symmetric, or a region only gets the cells reachable
+/// from its first one.
+pub fn components<'a, M, I, N, B, P>(
+    matrix: &'a M,
+    mut navigator: B,
+    mut predicate: P,
+) -> Components
+where
+    M: Cells + ?Sized,
+    I: Iterator<Item = Coord>,
+    N: Navigator<M, I>,
+    B: BorrowMut<N>,
+    P: FnMut((Coord, &'a M::Cell)) -> bool,
+{
+    let mut labels = (0..matrix.height())
+        .map(|row| vec![None; matrix.row_width(row)])
+        .collect::<Matrix<_>>();
+    let mut components = Vec::new();
+
+    for (start, value) in enum_iter(matrix) {
+        if labels[start.0][start.1].is_some() || !predicate((start, value)) {
+            continue;
+        }
+
+        let id = components.len();
+        let mut component = Component {
+            id,
+            size: 0,
+            bounds: (start, start),
+        };
+        labels[start.0][start.1] = Some(id);
+        let mut stack = vec![start];
+
+        while let Some(pos) = stack.pop() {
+            component.size += 1;
+            let (min, max) = &mut component.bounds;
+            *min = (min.0.min(pos.0), min.1.min(pos.1));
+            *max = (max.0.max(pos.0), max.1.max(pos.1));
+
+            for (next, value) in enum_navigate::<_, I, N, _>(matrix, &pos, navigator.borrow_mut()) {
+                if labels[next.0][next.1].is_none() && predicate((next, value)) {
+                    labels[next.0][next.1] = Some(id);
+                    stack.push(next);
+                }
+            }
+        }
+
+        components.push(component);
+    }
+
+    Components { labels, components }
+}
+
+#[cfg(test)]
+mod matrix_tests {
+    use super::{
+        astar, astar_by, bfs, bfs_path, cardinal_coords, chebyshev, components, dijkstra,
+        dijkstra_by, enum_iter, enum_iter_mut, flood_fill, king_coords, manhattan,
+        right_down_coords, square_coords, toroidal_coords, Component, Coord, Matrix,
+    };
+    use crate::utils::grid::Grid;
+
+    #[test]
+    fn ragged() {
+        let matrix: Matrix<u8> = vec![vec![], vec![1, 2], vec![], vec![], vec![3], vec![]];
+
+        let iter = enum_iter(&matrix);
+        assert_eq!(iter.len(), 3);
+        assert_eq!(
+            iter.collect::<Vec<_>>(),
+            [((1, 0), &1), ((1, 1), &2), ((4, 0), &3)]
+        );
+        assert_eq!(
+            enum_iter(&matrix).rev().collect::<Vec<_>>(),
+            [((4, 0), &3), ((1, 1), &2), ((1, 0), &1)]
+        );
+
+        let mut iter = enum_iter(&matrix);
+        assert_eq!(iter.next_back(), Some(((4, 0), &3)));
+        assert_eq!(iter.next(), Some(((1, 0), &1)));
+        assert_eq!(iter.len(), 1);
+        assert_eq!(iter.next_back(), Some(((1, 1), &2)));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+
+        assert_eq!(enum_iter(&Matrix::<u8>::new()).next(), None);
+        assert_eq!(enum_iter(&vec![Vec::<u8>::new(); 3]).next_back(), None);
+    }
+
+    #[test]
+    fn mutable() {
+        let mut matrix: Matrix<usize> = vec![vec![0, 0], vec![], vec![0]];
+        let mut iter = enum_iter_mut(&mut matrix);
+        assert_eq!(iter.len(), 3);
+        let ((i, j), value) = iter.next_back().unwrap();
+        *value = i * 10 + j + 1;
+        for ((i, j), value) in iter {
+            *value = i * 10 + j + 1;
+        }
+        assert_eq!(matrix, [vec![1, 2], vec![], vec![21]]);
+
+        let mut grid = Grid::from_rows(vec![vec![0; 3]; 2]).unwrap();
+        for (n, (_, value)) in enum_iter_mut(&mut grid).rev().enumerate() {
+            *value = n;
+        }
+        assert_eq!(grid.into_rows(), [[5, 4, 3], [2, 1, 0]]);
+    }
+
+    #[test]
+    fn navigators() {
+        let grid = Grid::new(3, 3, 0u8);
+        fn coords(iter: impl Iterator<Item = Coord>) -> Vec<Coord> {
+            iter.collect()
+        }
+
+        assert_eq!(
+            coords(cardinal_coords(&grid, &(0, 1))),
+            [(0, 0), (0, 2), (1, 1)]
+        );
+        assert_eq!(
+            coords(king_coords(&grid, &(1, 0))),
+            [(0, 0), (0, 1), (1, 1), (2, 0), (2, 1)]
+        );
+        assert_eq!(coords(square_coords(&grid, &(1, 1))).len(), 9);
+        assert_eq!(
+            coords(square_coords(&grid, &(2, 2))),
+            [(1, 1), (1, 2), (2, 1), (2, 2)]
+        );
+        assert_eq!(coords(right_down_coords(&grid, &(1, 2))), [(2, 2)]);
+        assert_eq!(
+            coords(toroidal_coords(&grid, &(0, 2))),
+            [(2, 2), (0, 1), (0, 0), (1, 2)]
+        );
+        assert_eq!(coords(king_coords(&grid, &(3, 0))), []);
+
+        let ragged: Matrix<u8> = vec![vec![0, 0, 0], vec![0]];
+        assert_eq!(coords(cardinal_coords(&ragged, &(0, 2))), [(0, 1)]);
+        assert_eq!(coords(toroidal_coords(&ragged, &(1, 0))), [(0, 0)]);
+        assert_eq!(
+            coords(toroidal_coords(&Grid::new(1, 2, 0u8), &(0, 0))),
+            [(0, 1)]
+        );
+    }
+
+    #[test]
+    fn paths() {
+        let matrix: Matrix<u32> = vec![
+            vec![1, 1, 6, 3],
+            vec![1, 3, 8, 1],
+            vec![2, 1, 3, 6],
+            vec![3, 6, 9, 4],
+        ];
+        let expected = Some((
+            vec![(0, 0), (1, 0), (2, 0), (2, 1), (2, 2), (2, 3), (3, 3)],
+            17,
+        ));
+        assert_eq!(dijkstra(&matrix, (0, 0), (3, 3), cardinal_coords), expected);
+        assert_eq!(
+            astar(&matrix, (0, 0), (3, 3), cardinal_coords, |pos: &Coord| {
+                manhattan(pos, &(3, 3)) as u32
+            }),
+            expected
+        );
+
+        // The Manhattan distance overestimates diagonal moves and free cells
+        let kings: Matrix<u32> = vec![vec![1, 2, 2, 1], vec![1, 2, 2, 3], vec![3, 2, 3, 1]];
+        let cost = |path: Option<(_, u32)>| path.map(|(_, cost)| cost);
+        assert_eq!(cost(dijkstra(&kings, (0, 0), (2, 3), king_coords)), Some(5));
+        assert_eq!(
+            cost(astar(&kings, (0, 0), (2, 3), king_coords, |pos: &Coord| {
+                chebyshev(pos, &(2, 3)) as u32
+            })),
+            cost(dijkstra(&kings, (0, 0), (2, 3), king_coords))
+        );
+        let free: Matrix<u32> = vec![vec![1, 2, 0, 1], vec![0, 2, 1, 0], vec![0, 2, 2, 1]];
+        assert_eq!(
+            cost(dijkstra(&free, (0, 0), (2, 3), cardinal_coords)),
+            Some(4)
+        );
+        assert_eq!(
+            cost(astar(&free, (0, 0), (2, 3), cardinal_coords, |_: &Coord| 0)),
+            cost(dijkstra(&free, (0, 0), (2, 3), cardinal_coords))
+        );
+
+        // Walls of 9 and the number of moves as the cost
+        let steps = |_: (_, &u32), (_, &v): (_, &u32)| if v == 9 { 1000 } else { 1 };
+        assert_eq!(
+            dijkstra_by(
+                &matrix,
+                &[(0, 3), (3, 0)],
+                &[(3, 3)],
+                cardinal_coords,
+                steps
+            ),
+            Some((vec![(0, 3), (1, 3), (2, 3), (3, 3)], 3))
+        );
+        assert_eq!(
+            astar_by(
+                &matrix,
+                &[(3, 0)],
+                &[(3, 3), (0, 3)],
+                cardinal_coords,
+                steps,
+                |pos| manhattan(pos, &(3, 3)).min(manhattan(pos, &(0, 3))),
+            )
+            .map(|(path, cost)| (path.len(), cost)),
+            Some((6, 5))
+        );
+        assert_eq!(
+            dijkstra_by(&matrix, &[(0, 0)], &[(9, 9)], cardinal_coords, steps),
+            None
+        );
+
+        let distances = bfs(&matrix, &[(0, 0), (3, 3)], cardinal_coords);
+        assert_eq!(distances.len(), 16);
+        assert_eq!(distances[&(0, 0)], 0);
+        assert_eq!(distances[&(1, 2)], 3);
+        assert_eq!(distances[&(3, 0)], 3);
+        assert_eq!(
+            bfs_path(&matrix, &[(0, 0)], &[(2, 2), (3, 3)], cardinal_coords).map(|path| path.len()),
+            Some(5)
+        );
+        assert_eq!(
+            bfs_path(&matrix, &[(0, 0)], &[(3, 3)], right_down_coords).map(|path| path.len()),
+            Some(7)
+        );
+    }
+
+    #[test]
+    fn regions() {
+        let grid = Grid::from_rows(
+            ["##..#", "#..##", "..##.", "##.#."]
+                .iter()
+                .map(|row| row.chars().collect())
+                .collect(),
+        )
+        .unwrap();
+        let wall = |(_, &c): (_, &char)| c == '#';
+
+        assert_eq!(
+            flood_fill(&grid, (0, 0), cardinal_coords, wall),
+            [(0, 0), (0, 1), (1, 0)].into_iter().collect()
+        );
+        assert!(flood_fill(&grid, (0, 2), cardinal_coords, wall).is_empty());
+        assert_eq!(flood_fill(&grid, (0, 0), king_coords, wall).len(), 3);
+
+        let found = components(&grid, cardinal_coords, wall);
+        assert_eq!(
+            found.components,
+            [
+                Component {
+                    id: 0,
+                    size: 3,
+                    bounds: ((0, 0), (1, 1)),
+                },
+                Component {
+                    id: 1,
+                    size: 6,
+                    bounds: ((0, 2), (3, 4)),
+                },
+                Component {
+                    id: 2,
+                    size: 2,
+                    bounds: ((3, 0), (3, 1)),
+                },
+            ]
+        );
+        assert_eq!(found.labels[3], [Some(2), Some(2), None, Some(1), None]);
+
+        // The walls touch diagonally
+        let found = components(&grid, king_coords, wall);
+        assert_eq!(found.components.len(), 2);
+        assert_eq!(found.components[0].size, 3);
+        assert_eq!(found.components[1].size, 8);
+        assert_eq!(found.components[1].bounds, ((0, 0), (3, 4)));
+    }
 }
@@ -1,6 +1,8 @@
 pub mod dec;
+pub mod grid;
 pub mod inc;
 pub mod matrix;
+pub mod ring;
 
 use std::{
     borrow::Borrow,
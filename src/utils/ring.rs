@@ -0,0 +1,220 @@
+use std::{
+    fmt,
+    iter::Chain,
+    ops::{Index, IndexMut},
+    slice, vec,
+};
+
+/// A buffer of fixed length whose start can be rotated in constant time.
+///
+/// Indexes and iterators are relative to the current start: after `forth`,
+/// index 0 is what used to be index 1, and the old index 0 is now the last.
+#[derive(Clone)]
+pub struct FixedRingBuffer<T> {
+    buffer: Vec<T>,
+    head: usize,
+}
+
+pub type Iter<'a, T> = Chain<slice::Iter<'a, T>, slice::Iter<'a, T>>;
+pub type IterMut<'a, T> = Chain<slice::IterMut<'a, T>, slice::IterMut<'a, T>>;
+
+impl<T> FixedRingBuffer<T> {
+    pub fn new_with_buffer(buffer: Vec<T>) -> Self {
+        Self { buffer, head: 0 }
+    }
+
+    fn calc_index(&self, idx: usize) -> Option<usize> {
+        if idx < self.buffer.len() {
+            Some((idx + self.head) % self.len())
+        } else {
+            None
+        }
+    }
+
+    pub fn get(&self, idx: usize) -> Option<&T> {
+        self.calc_index(idx)
+            .map(|index| unsafe { self.buffer.get_unchecked(index) })
+    }
+
+    pub fn get_mut(&mut self, idx: usize) -> Option<&mut T> {
+        self.calc_index(idx)
+            .map(|index| unsafe { self.buffer.get_unchecked_mut(index) })
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.buffer.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.buffer.is_empty()
+    }
+
+    /// Moves the start back by one, the last item becoming the first.
+    pub fn back(&mut self) {
+        self.rotate_by(-1);
+    }
+
+    /// Moves the start forth by one, the first item becoming the last.
+    pub fn forth(&mut self) {
+        self.rotate_by(1);
+    }
+
+    /// Moves the start by `n`, forth when positive and back when negative.
+    pub fn rotate_by(&mut self, n: isize) {
+        if self.is_empty() {
+            return;
+        }
+        let len = self.len() as isize;
+        self.head = (self.head as isize + n % len).rem_euclid(len) as usize;
+    }
+
+    /// The items from the current start.
+    pub fn iter(&self) -> Iter<'_, T> {
+        let (before, after) = self.buffer.split_at(self.head);
+        after.iter().chain(before)
+    }
+
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        let (before, after) = self.buffer.split_at_mut(self.head);
+        after.iter_mut().chain(before)
+    }
+}
+
+impl<T: Default> FixedRingBuffer<T> {
+    pub fn new(len: usize) -> Self {
+        Self::new_with_buffer((0..len).map(|_| Default::default()).collect())
+    }
+}
+
+impl<T> IntoIterator for FixedRingBuffer<T> {
+    type Item = T;
+    type IntoIter = vec::IntoIter<T>;
+
+    fn into_iter(mut self) -> Self::IntoIter {
+        self.buffer.rotate_left(self.head);
+        self.buffer.into_iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a FixedRingBuffer<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut FixedRingBuffer<T> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl<T> FromIterator<T> for FixedRingBuffer<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self::new_with_buffer(iter.into_iter().collect())
+    }
+}
+
+/// Lists the items from the current start, hiding where the buffer starts.
+impl<T: fmt::Debug> fmt::Debug for FixedRingBuffer<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+/// Buffers are equal when they list the same items from their current start,
+/// however they were rotated to get there.
+impl<T: PartialEq> PartialEq for FixedRingBuffer<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len() && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Eq> Eq for FixedRingBuffer<T> {}
+
+impl<T> Index<usize> for FixedRingBuffer<T> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        if let Some(val) = self.get(index) {
+            val
+        } else {
+            panic!(
+                "index out of bounds: the len is {} but the index is {}",
+                self.len(),
+                index,
+            )
+        }
+    }
+}
+
+impl<T> IndexMut<usize> for FixedRingBuffer<T> {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        let len = self.len();
+        if let Some(val) = self.get_mut(index) {
+            val
+        } else {
+            panic!(
+                "index out of bounds: the len is {} but the index is {}",
+                len, index,
+            )
+        }
+    }
+}
+
+#[cfg(test)]
+mod ring_tests {
+    use super::FixedRingBuffer;
+
+    #[test]
+    fn rotation() {
+        let mut ring = (0..5).collect::<FixedRingBuffer<_>>();
+        assert_eq!(ring[0], 0);
+
+        ring.forth();
+        assert_eq!(ring.iter().copied().collect::<Vec<_>>(), [1, 2, 3, 4, 0]);
+        assert_eq!(ring[4], 0);
+        assert_eq!(ring.get(5), None);
+
+        ring.back();
+        ring.back();
+        assert_eq!(ring.iter().copied().collect::<Vec<_>>(), [4, 0, 1, 2, 3]);
+
+        ring.rotate_by(12);
+        assert_eq!(ring.iter().copied().collect::<Vec<_>>(), [1, 2, 3, 4, 0]);
+        ring.rotate_by(-7);
+        assert_eq!(ring.iter().copied().collect::<Vec<_>>(), [4, 0, 1, 2, 3]);
+        assert_eq!(
+            ring.clone().into_iter().collect::<Vec<_>>(),
+            [4, 0, 1, 2, 3]
+        );
+
+        let mut empty = FixedRingBuffer::<u8>::new(0);
+        empty.rotate_by(3);
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn mutation() {
+        let mut ring = FixedRingBuffer::new(4);
+        ring.rotate_by(3);
+        for (i, value) in ring.iter_mut().enumerate() {
+            *value = i;
+        }
+        ring[1] *= 10;
+        assert_eq!(format!("{:?}", ring), "[0, 10, 2, 3]");
+
+        for value in &mut ring {
+            *value += 1;
+        }
+        assert_eq!(ring, [1, 11, 3, 4].into_iter().collect());
+        assert_ne!(ring, [11, 3, 4, 1].into_iter().collect());
+    }
+}
@@ -1,39 +1,301 @@
-#![allow(dead_code)]
+use std::{
+    collections::VecDeque,
+    ops::{AddAssign, SubAssign},
+};
 
-pub struct Window<T> {
-    window: Vec<T>,
-    size: usize,
+use crate::utils::ring::FixedRingBuffer;
+
+/// A value kept up to date as items enter and leave a [`Window`], in constant
+/// amortized time per item.
+pub trait Aggregate<T> {
+    type Output;
+
+    /// `value` entered the window.
+    fn push(&mut self, value: &T);
+
+    /// `value`, the oldest item of the window, left it.
+    fn evict(&mut self, value: &T);
+
+    fn get(&self) -> Self::Output;
+}
+
+/// No aggregate at all.
+impl<T> Aggregate<T> for () {
+    type Output = ();
+
+    fn push(&mut self, _value: &T) {}
+
+    fn evict(&mut self, _value: &T) {}
+
+    fn get(&self) -> Self::Output {}
+}
+
+/// Both aggregates at once.
+impl<T, A: Aggregate<T>, B: Aggregate<T>> Aggregate<T> for (A, B) {
+    type Output = (A::Output, B::Output);
+
+    fn push(&mut self, value: &T) {
+        self.0.push(value);
+        self.1.push(value);
+    }
+
+    fn evict(&mut self, value: &T) {
+        self.0.evict(value);
+        self.1.evict(value);
+    }
+
+    fn get(&self) -> Self::Output {
+        (self.0.get(), self.1.get())
+    }
+}
+
+/// The sum of the window.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Sum<T>(T);
+
+impl<T: Copy + AddAssign + SubAssign> Aggregate<T> for Sum<T> {
+    type Output = T;
+
+    fn push(&mut self, value: &T) {
+        self.0 += *value;
+    }
+
+    fn evict(&mut self, value: &T) {
+        self.0 -= *value;
+    }
+
+    fn get(&self) -> Self::Output {
+        self.0
+    }
+}
+
+/// The smallest item of the window, `None` when it is empty.
+///
+/// Only the items that can still become the minimum are kept, in increasing
+/// order: any item followed by a smaller one leaves the window before it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Min<T>(VecDeque<T>);
+
+/// The largest item of the window, `None` when it is empty.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Max<T>(VecDeque<T>);
+
+impl<T> Default for Min<T> {
+    fn default() -> Self {
+        Min(VecDeque::new())
+    }
+}
+
+impl<T> Default for Max<T> {
+    fn default() -> Self {
+        Max(VecDeque::new())
+    }
 }
 
-impl<T> Window<T> {
+/// Pushes `value` at the back of a monotonic queue, dropping the items that
+/// `value` outlives and beats.
+fn push_monotonic<T: Clone>(queue: &mut VecDeque<T>, value: &T, beats: impl Fn(&T) -> bool) {
+    while queue.back().is_some_and(&beats) {
+        queue.pop_back();
+    }
+    queue.push_back(value.clone());
+}
+
+/// Equal items are all kept, so evicting one of them removes a single copy.
+fn evict_monotonic<T: PartialEq>(queue: &mut VecDeque<T>, value: &T) {
+    if queue.front() == Some(value) {
+        queue.pop_front();
+    }
+}
+
+impl<T: Ord + Clone> Aggregate<T> for Min<T> {
+    type Output = Option<T>;
+
+    fn push(&mut self, value: &T) {
+        push_monotonic(&mut self.0, value, |back| back > value);
+    }
+
+    fn evict(&mut self, value: &T) {
+        evict_monotonic(&mut self.0, value);
+    }
+
+    fn get(&self) -> Self::Output {
+        self.0.front().cloned()
+    }
+}
+
+impl<T: Ord + Clone> Aggregate<T> for Max<T> {
+    type Output = Option<T>;
+
+    fn push(&mut self, value: &T) {
+        push_monotonic(&mut self.0, value, |back| back < value);
+    }
+
+    fn evict(&mut self, value: &T) {
+        evict_monotonic(&mut self.0, value);
+    }
+
+    fn get(&self) -> Self::Output {
+        self.0.front().cloned()
+    }
+}
+
+/// The last `size` items pushed, along with an aggregate of them.
+///
+/// The items live in a ring of `size` slots, the first `len` of which are
+/// filled. Once the window is full, the oldest slot is reused for the latest
+/// item and the ring moves forth.
+#[derive(Clone, Debug)]
+pub struct Window<T, A = ()> {
+    window: FixedRingBuffer<Option<T>>,
+    len: usize,
+    aggregate: A,
+}
+
+impl<T, A: Aggregate<T> + Default> Window<T, A> {
     pub fn new(size: usize) -> Self {
         Window {
-            window: Vec::new(),
-            size,
+            window: FixedRingBuffer::new(size),
+            len: 0,
+            aggregate: A::default(),
         }
     }
+}
 
+impl<T, A: Aggregate<T>> Window<T, A> {
     pub fn is_full(&self) -> bool {
-        self.window.len() == self.size
+        self.len == self.size()
     }
 
-    pub fn push(&mut self, value: T) {
-        if self.is_full() {
-            self.window.remove(0);
+    /// Pushes `value`, returning the item it pushed out of a full window.
+    pub fn push(&mut self, value: T) -> Option<T> {
+        if self.size() == 0 {
+            return Some(value);
+        }
+
+        let evicted = if self.is_full() {
+            let evicted = self.window[0].take();
+            self.window.forth();
+            evicted
+        } else {
+            self.len += 1;
+            None
+        };
+        if let Some(evicted) = &evicted {
+            self.aggregate.evict(evicted);
         }
 
-        self.window.push(value);
+        self.aggregate.push(&value);
+        self.window[self.len - 1] = Some(value);
+        evicted
     }
 
-    pub fn window(&self) -> &Vec<T> {
-        &self.window
+    /// The items from the oldest to the latest.
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &T> {
+        self.window.iter().flatten()
+    }
+
+    pub fn aggregate(&self) -> A::Output {
+        self.aggregate.get()
     }
 
     pub fn len(&self) -> usize {
-        self.window.len()
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
     }
 
     pub fn size(&self) -> usize {
-        self.size
+        self.window.len()
+    }
+}
+
+/// Iterator over the aggregate of every full window of an iterator, see
+/// [`WindowsOf::windows_of`].
+pub struct Windows<I: Iterator, A> {
+    iter: I,
+    window: Window<I::Item, A>,
+}
+
+impl<I: Iterator, A: Aggregate<I::Item>> Iterator for Windows<I, A> {
+    type Item = A::Output;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            self.window.push(self.iter.next()?);
+            if self.window.is_full() {
+                return Some(self.window.aggregate());
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // Items still needed before the first window is full
+        let missing = self.window.size().saturating_sub(self.window.len() + 1);
+        let (lower, upper) = self.iter.size_hint();
+        (
+            lower.saturating_sub(missing),
+            upper.map(|upper| upper.saturating_sub(missing)),
+        )
+    }
+}
+
+pub trait WindowsOf: Iterator + Sized {
+    /// Slides a window of `size` items over the iterator, yielding the
+    /// aggregate `A` of every full window, e.g. `windows_of::<Sum<_>>(3)`
+    /// for the sums of three consecutive items.
+    fn windows_of<A: Aggregate<Self::Item> + Default>(self, size: usize) -> Windows<Self, A> {
+        Windows {
+            iter: self,
+            window: Window::new(size),
+        }
+    }
+}
+
+impl<I: Iterator> WindowsOf for I {}
+
+#[cfg(test)]
+mod window_tests {
+    use super::{Max, Min, Sum, Window, WindowsOf};
+
+    #[test]
+    fn window() {
+        let mut window: Window<u32, Sum<u32>> = Window::new(3);
+        assert_eq!(window.push(1), None);
+        assert_eq!(window.push(2), None);
+        assert!(!window.is_full());
+        assert_eq!(window.push(3), None);
+        assert!(window.is_full());
+        assert_eq!(window.aggregate(), 6);
+
+        assert_eq!(window.push(4), Some(1));
+        assert_eq!(window.iter().copied().collect::<Vec<_>>(), [2, 3, 4]);
+        assert_eq!(window.aggregate(), 9);
+        assert_eq!(window.len(), 3);
+    }
+
+    #[test]
+    fn windows_of() {
+        let values = [3, 1, 4, 1, 5, 9, 2, 6, 5, 3, 5];
+
+        for size in 1..=values.len() + 1 {
+            let expected = values
+                .windows(size)
+                .map(|w| {
+                    (
+                        w.iter().sum::<i32>(),
+                        (w.iter().min().copied(), w.iter().max().copied()),
+                    )
+                })
+                .collect::<Vec<_>>();
+            let windows = values
+                .into_iter()
+                .windows_of::<(Sum<_>, (Min<_>, Max<_>))>(size);
+
+            assert_eq!(windows.size_hint().0, expected.len());
+            assert_eq!(windows.collect::<Vec<_>>(), expected);
+        }
     }
 }
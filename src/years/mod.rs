@@ -0,0 +1,34 @@
+pub mod y2021;
+
+use crate::solution::Solution;
+
+/// The solutions of a single Advent of Code event.
+pub struct Year {
+    pub year: usize,
+    pub days: &'static [&'static dyn Solution],
+}
+
+impl Year {
+    pub fn get(&self, day: usize) -> Option<&'static dyn Solution> {
+        self.days
+            .iter()
+            .copied()
+            .find(|solution| solution.day() == day)
+    }
+}
+
+/// Every supported year, oldest first. A new year only needs its own module
+/// with a `DAYS` registry and an entry here.
+pub const YEARS: &[Year] = &[Year {
+    year: 2021,
+    days: y2021::DAYS,
+}];
+
+/// The year used when none is given: the most recent one.
+pub fn latest() -> &'static Year {
+    YEARS.last().unwrap()
+}
+
+pub fn get(year: usize) -> Option<&'static Year> {
+    YEARS.iter().find(|y| y.year == year)
+}
@@ -0,0 +1,188 @@
+use std::{borrow::Borrow, collections::HashMap};
+
+use crate::error::{parse_lines, ParseResult};
+use crate::solution::{solution, ParseError};
+
+fn parse_patterns(i: usize, line: &str, text: &str) -> ParseResult<Vec<u8>> {
+    text.split_whitespace()
+        .map(|w| match w.find(|c| !('a'..='g').contains(&c)) {
+            Some(j) => Err(ParseError::at(
+                i,
+                line,
+                &w[j..],
+                format!("invalid segment in '{}'", w),
+            )),
+            None => Ok(make_bitmap(w.chars())),
+        })
+        .collect()
+}
+
+fn raw_parse(text: &str) -> ParseResult<Vec<([u8; 10], Vec<u8>)>> {
+    parse_lines(text, |i, line| {
+        let (wires, nums) = line
+            .split_once('|')
+            .ok_or_else(|| ParseError::new(i, 1, "missing '|' separator"))?;
+
+        let wires: [u8; 10] =
+            parse_patterns(i, line, wires)?
+                .try_into()
+                .map_err(|v: Vec<u8>| {
+                    ParseError::new(i, 1, format!("expected 10 patterns, found {}", v.len()))
+                })?;
+        let nums = parse_patterns(i, line, nums)?;
+
+        Ok((wires, nums))
+    })
+}
+
+/// The patterns of 1, 4, 7 and 8, then those with 5 and with 6 segments.
+type Split = (u8, u8, u8, u8, [u8; 3], [u8; 3]);
+
+/// `None` unless the patterns have the segment counts of the ten digits.
+fn split_values(values: [u8; 10]) -> Option<Split> {
+    let mut one = None;
+    let mut four = None;
+    let mut seven = None;
+    let mut eight = None;
+    let mut seg_5 = Vec::new();
+    let mut seg_6 = Vec::new();
+
+    for v in values {
+        match v.count_ones() {
+            2 => {
+                one = Some(v);
+            }
+            3 => {
+                seven = Some(v);
+            }
+            4 => {
+                four = Some(v);
+            }
+            5 => {
+                seg_5.push(v);
+            }
+            6 => {
+                seg_6.push(v);
+            }
+            7 => {
+                eight = Some(v);
+            }
+            _ => return None,
+        }
+    }
+
+    Some((
+        one?,
+        four?,
+        seven?,
+        eight?,
+        seg_5.try_into().ok()?,
+        seg_6.try_into().ok()?,
+    ))
+}
+
+fn make_bitmap<C: Borrow<char>, I: Iterator<Item = C>>(i: I) -> u8 {
+    i.map(|c| (*c.borrow() as u8) - b'a')
+        .fold(0, |acc, c| acc | (1 << c))
+}
+
+fn contains(a: u8, b: u8) -> bool {
+    a & b == b
+}
+
+fn extract(haystack: &mut Vec<u8>, find: impl Fn(u8) -> bool) -> Option<u8> {
+    let idx = haystack
+        .iter()
+        .enumerate()
+        .find_map(|(i, &n)| if find(n) { Some(i) } else { None })?;
+    Some(haystack.remove(idx))
+}
+
+fn extract_contains(haystack: &mut Vec<u8>, search: u8) -> Option<u8> {
+    extract(haystack, |n| contains(n, search))
+}
+
+/// `None` when the patterns cannot be the ten digits wired in some order.
+fn make_map(values: [u8; 10]) -> Option<HashMap<u8, usize>> {
+    let mut map = HashMap::new();
+    let (one, four, seven, eight, seg_5, seg_6) = split_values(values)?;
+    let mut seg_5 = seg_5.to_vec();
+    let mut seg_6 = seg_6.to_vec();
+
+    map.insert(one, 1);
+    map.insert(four, 4);
+    map.insert(seven, 7);
+    map.insert(eight, 8);
+    map.insert(extract_contains(&mut seg_5, one)?, 3);
+    map.insert(extract_contains(&mut seg_6, four)?, 9);
+    map.insert(extract_contains(&mut seg_6, one)?, 0);
+    let six = seg_6.remove(0);
+    map.insert(six, 6);
+    map.insert(extract(&mut seg_5, |n| contains(six, n))?, 5);
+    map.insert(seg_5.remove(0), 2);
+
+    Some(map)
+}
+
+fn parse(text: &str) -> ParseResult<Vec<Vec<usize>>> {
+    raw_parse(text)?
+        .into_iter()
+        .enumerate()
+        .map(|(i, (wires, nums))| {
+            let unsolvable = |what| ParseError::unsolvable(format!("entry {}: {}", i + 1, what));
+            let map =
+                make_map(wires).ok_or_else(|| unsolvable("the patterns are not ten digits"))?;
+            nums.into_iter()
+                .map(|n| {
+                    map.get(&n)
+                        .copied()
+                        .ok_or_else(|| unsolvable("the output is not one of the patterns"))
+                })
+                .collect()
+        })
+        .collect()
+}
+
+pub fn solution1(text: &str) -> ParseResult<usize> {
+    Ok(raw_parse(text)?
+        .into_iter()
+        .map(|(_, nums)| {
+            nums.into_iter()
+                .filter(|x| [2, 4, 3, 7].contains(&x.count_ones()))
+                .count()
+        })
+        .sum())
+}
+
+pub fn solution2(text: &str) -> ParseResult<usize> {
+    Ok(parse(text)?
+        .into_iter()
+        .map(|nums| nums.into_iter().fold(0, |acc, n| acc * 10 + n))
+        .sum())
+}
+
+solution!(8, "Seven Segment Search", parse);
+
+#[cfg(test)]
+mod eight_tests {
+    use super::solution2;
+    use crate::error::ParseError;
+
+    #[test]
+    fn invalid() {
+        let line = "acedgfb cdfbe gcdfa fbcad dab cefabd cdfgeb eafb cagedb ab |";
+        assert_eq!(solution2(&format!("{} cdfeb fcadb", line)), Ok(53));
+        assert_eq!(
+            solution2(&line.replace("ab |", "abc |")),
+            Err(ParseError::unsolvable(
+                "entry 1: the patterns are not ten digits"
+            ))
+        );
+        assert_eq!(
+            solution2(&format!("{} cdfeb ga", line)),
+            Err(ParseError::unsolvable(
+                "entry 1: the output is not one of the patterns"
+            ))
+        );
+    }
+}
@@ -6,7 +6,8 @@ use std::{
 
 use num::integer::Integer;
 
-const INPUT: &str = include_str!("../../inputs/18");
+use crate::error::{parse_lines, ParseResult};
+use crate::solution::{solution, ParseError};
 
 #[derive(Clone, PartialEq, Eq, Default)]
 pub struct Pair(NumberElement, NumberElement);
@@ -75,10 +76,7 @@ pub enum SplitResult {
 
 impl NumberElement {
     pub fn is_single(&self) -> bool {
-        match self {
-            Self::Single(_) => true,
-            _ => false,
-        }
+        matches!(self, Self::Single(_))
     }
 
     #[inline]
@@ -181,11 +179,8 @@ impl Pair {
         }
 
         if level > 3 {
-            match self {
-                Pair(NumberElement::Single(_), NumberElement::Single(_)) => {
-                    return ExplodeResult::Explode;
-                }
-                _ => (),
+            if let Pair(NumberElement::Single(_), NumberElement::Single(_)) = self {
+                return ExplodeResult::Explode;
             }
         }
 
@@ -198,7 +193,7 @@ impl Pair {
                 if let NumberElement::Single(i) = take(&mut self.0) {
                     self.0 = NumberElement::Pair(Box::new(Pair(
                         NumberElement::Single(i / 2),
-                        NumberElement::Single(i.div_ceil(&2)),
+                        NumberElement::Single(Integer::div_ceil(&i, &2)),
                     )));
                     SplitResult::Restart
                 } else {
@@ -217,7 +212,7 @@ impl Pair {
                 if let NumberElement::Single(i) = take(&mut self.1) {
                     self.1 = NumberElement::Pair(Box::new(Pair(
                         NumberElement::Single(i / 2),
-                        NumberElement::Single(i.div_ceil(&2)),
+                        NumberElement::Single(Integer::div_ceil(&i, &2)),
                     )));
                     SplitResult::Restart
                 } else {
@@ -242,7 +237,7 @@ impl Pair {
 }
 
 #[derive(Clone, Debug, Default, PartialEq, Eq)]
-struct Number(Pair);
+pub struct Number(Pair);
 
 impl std::fmt::Display for Number {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
@@ -250,9 +245,9 @@ impl std::fmt::Display for Number {
     }
 }
 
-impl Into<NumberElement> for Number {
-    fn into(self) -> NumberElement {
-        NumberElement::Pair(Box::new(self.0))
+impl From<Number> for NumberElement {
+    fn from(val: Number) -> Self {
+        NumberElement::Pair(Box::new(val.0))
     }
 }
 
@@ -317,11 +312,14 @@ impl Sum for Number {
             }
         }
 
-        acc.unwrap_or_else(|| Number(Pair(NumberElement::Single(0), NumberElement::Single(0))))
+        acc.unwrap_or(Number(Pair(
+            NumberElement::Single(0),
+            NumberElement::Single(0),
+        )))
     }
 }
 
-mod parser {
+pub mod parser {
     pub type ParseResult<'a, T> = (&'a str, T);
     pub type Result<'a, T, E> = std::result::Result<ParseResult<'a, T>, E>;
 
@@ -330,9 +328,7 @@ mod parser {
 
         fn parse<'a>(text: &'a str) -> Result<'a, Self, Self::Error>;
 
-        fn parse_all<'a>(
-            text: &'a str,
-        ) -> std::result::Result<Option<Self>, <Self as Parse>::Error> {
+        fn parse_all(text: &str) -> std::result::Result<Option<Self>, <Self as Parse>::Error> {
             match <Self as Parse>::parse(text) {
                 Ok((text, res)) => {
                     if text.is_empty() {
@@ -350,52 +346,54 @@ mod parser {
         <T as Parse>::parse(text)
     }
 
-    pub fn parse_all<'a, T: Parse>(
-        text: &'a str,
-    ) -> std::result::Result<Option<T>, <T as Parse>::Error> {
+    pub fn parse_all<T: Parse>(text: &str) -> std::result::Result<Option<T>, <T as Parse>::Error> {
         <T as Parse>::parse_all(text)
     }
 
     pub fn is_digit(c: char) -> bool {
-        c >= '0' && c <= '9'
+        c.is_ascii_digit()
     }
 
     pub fn to_digit(c: char) -> u8 {
-        (c as u8) - ('0' as u8)
+        (c as u8) - b'0'
     }
 
     #[inline]
-    pub fn next_char<'a>(text: &'a str) -> Option<(&'a str, char)> {
-        if let Some(c) = text.chars().nth(0) {
-            Some((&text[1..], c))
+    pub fn next_char(text: &str) -> Option<(&str, char)> {
+        if let Some(c) = text.chars().next() {
+            Some((&text[c.len_utf8()..], c))
         } else {
             None
         }
     }
 
+    /// Consumes `expected`, failing with the length of the text left.
+    fn expect(text: &str, expected: char) -> Result<'_, char, usize> {
+        match next_char(text) {
+            Some((text, c)) if c == expected => Ok((text, c)),
+            _ => Err(text.len()),
+        }
+    }
+
+    // The errors are the length of the text left where parsing failed, so
+    // that callers can locate them in the original text
+
     impl Parse for super::Pair {
-        type Error = ();
-
-        fn parse<'a>(
-            text: &'a str,
-        ) -> std::result::Result<(&'a str, Self), <Self as Parse>::Error> {
-            if let Some((text, '[')) = next_char(text) {
-                let (text, left) = parse(text)?;
-                if let Some((text, ',')) = next_char(text) {
-                    let (text, right) = parse(text)?;
-
-                    if let Some((text, ']')) = next_char(text) {
-                        return Ok((text, super::Pair(left, right)));
-                    }
-                }
-            }
+        type Error = usize;
 
-            Err(())
+        fn parse(text: &str) -> std::result::Result<(&str, Self), <Self as Parse>::Error> {
+            let (text, _) = expect(text, '[')?;
+            let (text, left) = parse(text)?;
+            let (text, _) = expect(text, ',')?;
+            let (text, right) = parse(text)?;
+            let (text, _) = expect(text, ']')?;
+
+            Ok((text, super::Pair(left, right)))
         }
     }
 
     impl Parse for super::NumberElement {
-        type Error = ();
+        type Error = usize;
 
         fn parse<'a>(text: &'a str) -> Result<'a, Self, <Self as Parse>::Error> {
             if let Some((text, c)) = next_char(text) {
@@ -404,48 +402,54 @@ mod parser {
                 }
             }
 
-            match parse(text) {
-                Ok((text, pair)) => Ok((text, super::NumberElement::Pair(Box::new(pair)))),
-                Err(_) => Err(()),
-            }
+            parse(text).map(|(text, pair)| (text, super::NumberElement::Pair(Box::new(pair))))
         }
     }
 
     impl Parse for super::Number {
-        type Error = ();
+        type Error = usize;
 
         fn parse<'a>(text: &'a str) -> Result<'a, Self, <Self as Parse>::Error> {
-            match parse(text) {
-                Ok((text, pair)) => Ok((text, super::Number(pair))),
-                Err(_) => Err(()),
-            }
+            parse(text).map(|(text, pair)| (text, super::Number(pair)))
         }
     }
 }
 
-fn parse_line(text: &str) -> Option<Number> {
-    match self::parser::parse_all(text.trim()) {
-        Err(_) => None,
-        Ok(res) => res.map(|mut n: Number| {
+fn parse_line(i: usize, line: &str) -> ParseResult<Number> {
+    let text = line.trim();
+
+    match self::parser::parse::<Number>(text) {
+        Ok(("", mut n)) => {
             n.reduce();
-            n
-        }),
+            Ok(n)
+        }
+        Ok((rest, _)) => Err(ParseError::at(
+            i,
+            line,
+            rest,
+            "unexpected trailing characters",
+        )),
+        Err(left) => {
+            let rest = &text[text.len() - left..];
+            let message = match rest.chars().next() {
+                Some(c) => format!("unexpected character '{}'", c),
+                None => "unexpected end of line".to_string(),
+            };
+            Err(ParseError::at(i, line, rest, message))
+        }
     }
 }
 
-fn parse(text: &str) -> Vec<Number> {
-    text.trim()
-        .lines()
-        .map(|line| parse_line(line).unwrap())
-        .collect()
+fn parse(text: &str) -> ParseResult<Vec<Number>> {
+    parse_lines(text, parse_line)
 }
 
-pub(crate) fn solution1(text: &str) -> usize {
-    parse(text).into_iter().sum::<Number>().magnitude()
+pub fn solution1(text: &str) -> ParseResult<usize> {
+    Ok(parse(text)?.into_iter().sum::<Number>().magnitude())
 }
 
-pub(crate) fn solution2(text: &str) -> usize {
-    let numbers = parse(text);
+pub fn solution2(text: &str) -> ParseResult<usize> {
+    let numbers = parse(text)?;
     let mut max = None;
 
     for i in 0..numbers.len() {
@@ -457,223 +461,97 @@ pub(crate) fn solution2(text: &str) -> usize {
         }
     }
 
-    max.unwrap()
+    max.ok_or_else(|| {
+        ParseError::new(
+            numbers.len() + 1,
+            1,
+            format!("expected at least 2 numbers, found {}", numbers.len()),
+        )
+    })
 }
 
-pub fn solution() {
-    println!("Solution 1: {}", solution1(INPUT));
-    println!("Solution 2: {}", solution2(INPUT));
-}
+solution!(18, "Snailfish", parse);
 
 #[cfg(test)]
 mod eighteen_tests {
     use super::{parse, parse_line, solution2, Number, NumberElement, Pair};
-
-    #[inline]
-    fn eq(text: &str) {
-        let n = parse_line(text);
-        assert!(n.is_some());
-        let n = n.unwrap();
-        assert_eq!(text, n.to_string());
-    }
-
-    #[test]
-    fn test1() {
-        eq("[1,2]");
-    }
+    use crate::error::ParseError;
 
     #[test]
-    fn test2() {
-        eq("[[1,2],3]");
-    }
-
-    #[test]
-    fn test3() {
-        eq("[9,[8,7]]");
-    }
-
-    #[test]
-    fn test4() {
-        eq("[[1,9],[8,5]]");
+    fn display() {
+        for text in ["[1,2]", "[[1,2],3]", "[9,[8,7]]", "[[1,9],[8,5]]"] {
+            assert_eq!(
+                parse_line(1, text).map(|n| n.to_string()),
+                Ok(text.to_owned())
+            );
+        }
     }
 
     #[test]
-    fn test5() {
-        let n1 = parse_line("[1,2]");
-        assert!(n1.is_some());
-        let n1 = n1.unwrap();
-        let n2 = parse_line("[[3,4],5]");
-        assert!(n2.is_some());
-        let n2 = n2.unwrap();
-        let n3 = n1.concat(n2);
-        let result = parse_line("[[1,2],[[3,4],5]]");
-        assert!(result.is_some());
-        let result = result.unwrap();
-        assert_eq!(result, n3);
-    }
+    fn concat() {
+        let n1 = parse_line(1, "[1,2]").unwrap();
+        let n2 = parse_line(1, "[[3,4],5]").unwrap();
+        let expected = parse_line(1, "[[1,2],[[3,4],5]]");
+        assert_eq!(Ok(n1.clone().concat(n2.clone())), expected);
 
-    #[test]
-    fn test6() {
-        let n1 = parse_line("[1,2]");
-        assert!(n1.is_some());
-        let mut n1 = n1.unwrap();
-        let n2 = parse_line("[[3,4],5]");
-        assert!(n2.is_some());
-        let n2 = n2.unwrap();
-        n1.concat_assign(n2);
-        let result = parse_line("[[1,2],[[3,4],5]]");
-        assert!(result.is_some());
-        let result = result.unwrap();
-        assert_eq!(result, n1);
+        let mut n = n1;
+        n.concat_assign(n2);
+        assert_eq!(Ok(n), expected);
     }
 
     #[test]
-    fn test7() {
+    fn reduce() {
         let mut n = Number(Pair(NumberElement::Single(11), NumberElement::Single(1)));
         n.reduce();
         assert_eq!(n.to_string(), "[[5,6],1]");
-    }
 
-    #[test]
-    fn test8() {
-        assert_eq!(
-            parse_line("[[[[[9,8],1],2],3],4]").map(|n| n.to_string()),
-            Some("[[[[0,9],2],3],4]".to_owned())
-        );
-    }
-
-    #[test]
-    fn test9() {
-        assert_eq!(
-            parse_line("[7,[6,[5,[4,[3,2]]]]]").map(|n| n.to_string()),
-            Some("[7,[6,[5,[7,0]]]]".to_owned())
-        );
+        // Numbers are reduced as they are parsed
+        let reduced = [
+            ("[[[[[9,8],1],2],3],4]", "[[[[0,9],2],3],4]"),
+            ("[7,[6,[5,[4,[3,2]]]]]", "[7,[6,[5,[7,0]]]]"),
+            ("[[6,[5,[4,[3,2]]]],1]", "[[6,[5,[7,0]]],3]"),
+            (
+                "[[3,[2,[1,[7,3]]]],[6,[5,[4,[3,2]]]]]",
+                "[[3,[2,[8,0]]],[9,[5,[7,0]]]]",
+            ),
+            (
+                "[[3,[2,[8,0]]],[9,[5,[4,[3,2]]]]]",
+                "[[3,[2,[8,0]]],[9,[5,[7,0]]]]",
+            ),
+        ];
+        for (text, expected) in reduced {
+            assert_eq!(
+                parse_line(1, text).map(|n| n.to_string()),
+                Ok(expected.to_owned())
+            );
+        }
     }
 
     #[test]
-    fn test10() {
+    fn invalid() {
         assert_eq!(
-            parse_line("[[6,[5,[4,[3,2]]]],1]").map(|n| n.to_string()),
-            Some("[[6,[5,[7,0]]],3]".to_owned())
+            parse("[1,2]\n[[1,2],x]"),
+            Err(ParseError::new(2, 8, "unexpected character 'x'"))
         );
-    }
-
-    #[test]
-    fn test11() {
         assert_eq!(
-            parse_line("[[3,[2,[1,[7,3]]]],[6,[5,[4,[3,2]]]]]").map(|n| n.to_string()),
-            Some("[[3,[2,[8,0]]],[9,[5,[7,0]]]]".to_owned())
+            parse("[1,2]]").unwrap_err().message,
+            "unexpected trailing characters"
         );
-    }
-
-    #[test]
-    fn test12() {
         assert_eq!(
-            parse_line("[[3,[2,[8,0]]],[9,[5,[4,[3,2]]]]]").map(|n| n.to_string()),
-            Some("[[3,[2,[8,0]]],[9,[5,[7,0]]]]".to_owned())
-        );
-    }
-
-    fn test_add(text: &str, result: &str) -> Number {
-        let numbers = parse(text);
-        let sum: Number = numbers.into_iter().sum();
-        let result = parse_line(result);
-        assert!(result.is_some());
-        let result = result.unwrap();
-        assert_eq!(sum.to_string(), result.to_string());
-        result
-    }
-
-    #[test]
-    fn test13() {
-        test_add(
-            "[1,1]
-[2,2]
-[3,3]
-[4,4]",
-            "[[[[1,1],[2,2]],[3,3]],[4,4]]",
+            parse("[1,[2").unwrap_err(),
+            ParseError::new(1, 6, "unexpected end of line")
         );
     }
 
     #[test]
-    fn test14() {
-        test_add(
-            "[1,1]
-[2,2]
-[3,3]
-[4,4]
-[5,5]",
-            "[[[[3,0],[5,3]],[4,4]],[5,5]]",
-        );
-    }
-
-    #[test]
-    fn test15() {
-        test_add(
-            "[1,1]
-[2,2]
-[3,3]
-[4,4]
-[5,5]
-[6,6]",
-            "[[[[5,0],[7,4]],[5,5]],[6,6]]",
-        );
-    }
-
-    #[test]
-    fn test16() {
-        test_add(
-            "[[[0,[4,5]],[0,0]],[[[4,5],[2,6]],[9,5]]]
-[7,[[[3,7],[4,3]],[[6,3],[8,8]]]]
-[[2,[[0,8],[3,4]]],[[[6,7],1],[7,[1,6]]]]
-[[[[2,4],7],[6,[0,5]]],[[[6,8],[2,8]],[[2,1],[4,5]]]]
-[7,[5,[[3,8],[1,4]]]]
-[[2,[2,2]],[8,[8,1]]]
-[2,9]
-[1,[[[9,3],9],[[9,0],[0,7]]]]
-[[[5,[7,4]],7],1]
-[[[[4,2],2],6],[8,7]]",
-            "[[[[8,7],[7,7]],[[8,6],[7,7]]],[[[0,7],[6,6]],[8,7]]]",
-        );
-    }
-
-    #[test]
-    fn test17() {
+    fn too_few() {
         assert_eq!(
-            test_add(
-                "[[[0,[5,8]],[[1,7],[9,6]]],[[4,[1,2]],[[1,4],2]]]
-[[[5,[2,8]],4],[5,[[9,9],0]]]
-[6,[[[6,2],[5,6]],[[7,6],[4,7]]]]
-[[[6,[0,7]],[0,9]],[4,[9,[9,0]]]]
-[[[7,[6,4]],[3,[1,3]]],[[[5,5],1],9]]
-[[6,[[7,3],[3,2]]],[[[3,8],[5,7]],4]]
-[[[[5,4],[7,7]],8],[[8,3],8]]
-[[9,3],[[9,9],[6,[4,9]]]]
-[[2,[[7,7],7]],[[5,8],[[9,3],[0,2]]]]
-[[[[5,2],5],[8,[3,7]]],[[5,[7,5]],[4,4]]]",
-                "[[[[6,6],[7,6]],[[7,7],[7,0]]],[[[7,7],[7,7]],[[7,8],[9,9]]]]",
-            )
-            .magnitude(),
-            4140
-        );
-    }
-
-    #[test]
-    fn test18() {
-        assert_eq!(
-            solution2(
-                "[[[0,[5,8]],[[1,7],[9,6]]],[[4,[1,2]],[[1,4],2]]]
-[[[5,[2,8]],4],[5,[[9,9],0]]]
-[6,[[[6,2],[5,6]],[[7,6],[4,7]]]]
-[[[6,[0,7]],[0,9]],[4,[9,[9,0]]]]
-[[[7,[6,4]],[3,[1,3]]],[[[5,5],1],9]]
-[[6,[[7,3],[3,2]]],[[[3,8],[5,7]],4]]
-[[[[5,4],[7,7]],8],[[8,3],8]]
-[[9,3],[[9,9],[6,[4,9]]]]
-[[2,[[7,7],7]],[[5,8],[[9,3],[0,2]]]]
-[[[[5,2],5],[8,[3,7]]],[[5,[7,5]],[4,4]]]"
-            ),
-            3993
+            solution2("[1,2]"),
+            Err(ParseError::new(
+                2,
+                1,
+                "expected at least 2 numbers, found 1"
+            ))
         );
     }
 }
@@ -0,0 +1,88 @@
+use fifo_set::FIFOSet;
+
+use crate::error::{parse_grid, ParseResult};
+use crate::solution::solution;
+use crate::utils::{grid::Grid, inc::IncAssign, matrix::king_coords};
+
+fn parse(text: &str) -> ParseResult<Grid<u8>> {
+    parse_grid(text, |c| c.to_digit(10).map(|d| d as u8))
+}
+
+fn inc_all<T: IncAssign + Ord>(matrix: &mut Grid<T>, max: &T) -> FIFOSet<(usize, usize)> {
+    let mut flashing = FIFOSet::new();
+
+    for i in 0..matrix.height() {
+        for (j, value) in matrix.row_mut(i).iter_mut().enumerate() {
+            value.inc_assign();
+
+            if (*value).gt(max) {
+                flashing.push((i, j));
+            }
+        }
+    }
+
+    flashing
+}
+
+fn flash<T: IncAssign + PartialOrd>(
+    matrix: &mut Grid<T>,
+    max: &T,
+    pos: (usize, usize),
+    stack: &mut FIFOSet<(usize, usize)>,
+) {
+    for next in king_coords(&*matrix, &pos) {
+        if matrix[next].le(max) {
+            matrix[next].inc_assign();
+            if matrix[next].gt(max) {
+                stack.push(next);
+            }
+        }
+    }
+}
+
+fn flash_all<T: IncAssign + Ord>(
+    matrix: &mut Grid<T>,
+    max: &T,
+    mut stack: FIFOSet<(usize, usize)>,
+) {
+    while let Some(pos) = stack.pop() {
+        if matrix[pos].gt(max) {
+            flash(matrix, max, pos, &mut stack);
+        }
+    }
+}
+
+fn count_and_reset<T: Clone + Ord>(matrix: &mut Grid<T>, min: &T, max: &T) -> usize {
+    let mut count: usize = 0;
+    for value in matrix.iter_mut() {
+        if (*value).gt(max) {
+            count += 1;
+            *value = min.clone();
+        }
+    }
+
+    count
+}
+
+fn evolve<T: IncAssign + Ord + Clone>(matrix: &mut Grid<T>, min: &T, max: &T) -> usize {
+    let flashing = inc_all(matrix, max);
+    flash_all(matrix, max, flashing);
+    count_and_reset(matrix, min, max)
+}
+
+pub fn solution1(text: &str) -> ParseResult<usize> {
+    let mut matrix = parse(text)?;
+    Ok((0..100).fold(0usize, |acc, _| acc + evolve(&mut matrix, &0, &9)))
+}
+
+pub fn solution2(text: &str) -> ParseResult<usize> {
+    let mut matrix = parse(text)?;
+    let count = matrix.width() * matrix.height();
+    let mut step = 1;
+    while evolve(&mut matrix, &0, &9) != count {
+        step += 1;
+    }
+    Ok(step)
+}
+
+solution!(11, "Dumbo Octopus", parse);
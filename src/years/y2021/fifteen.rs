@@ -0,0 +1,106 @@
+use num_traits::{FromPrimitive, Zero};
+
+use crate::error::{parse_grid, ParseResult};
+use crate::solution::{solution, ParseError};
+use crate::utils::{
+    grid::Grid,
+    inc::IncAssign,
+    matrix::{astar, cardinal_coords, manhattan, Matrix},
+};
+
+/// Risk levels go from 1 to 9, so the Manhattan distance never overestimates
+/// the risk left.
+fn parse(text: &str) -> ParseResult<Matrix<usize>> {
+    parse_grid(text, |c| {
+        c.to_digit(10).filter(|&d| d > 0).map(|d| d as usize)
+    })
+    .map(Grid::into_rows)
+}
+
+/// The lowest total risk from the top left to the bottom right.
+fn solve<T>(matrix: &Matrix<T>) -> ParseResult<T>
+where
+    T: Copy + Ord + Zero + FromPrimitive,
+{
+    let end = match matrix.last() {
+        Some(row) if !row.is_empty() => (matrix.len() - 1, row.len() - 1),
+        _ => return Err(ParseError::new(1, 1, "empty map")),
+    };
+
+    // Too large a distance for the risk type cannot guide anything
+    let heuristic = |pos: &_| T::from_usize(manhattan(pos, &end)).unwrap_or_else(T::zero);
+    astar(matrix, (0, 0), end, cardinal_coords, heuristic)
+        .map(|(_, risk)| risk)
+        .ok_or_else(|| ParseError::unsolvable("no path to the bottom right"))
+}
+
+fn inc_all<T: Clone + Ord + IncAssign>(matrix: &mut Matrix<T>, min: &T, max: &T) {
+    for line in matrix.iter_mut() {
+        for cell in line.iter_mut() {
+            cell.inc_assign();
+            if PartialOrd::gt(cell, max) {
+                *cell = Clone::clone(min);
+            }
+        }
+    }
+}
+
+fn mul<T: Clone + Ord + IncAssign>(matrix: Matrix<T>, times: usize, min: &T, max: &T) -> Matrix<T> {
+    let max_times = times * 2 - 1;
+    let mut i_times = 1;
+    let mut i_range = min.clone().inc();
+    let mut squares: Vec<Matrix<T>> = Vec::new();
+    squares.push(matrix);
+
+    while i_times < max_times && i_range.le(max) {
+        squares.push(squares[squares.len() - 1].clone());
+        let last_idx = squares.len() - 1;
+        inc_all(&mut squares[last_idx], min, max);
+        i_times.inc_assign();
+        i_range.inc_assign();
+    }
+
+    let mut res: Matrix<T> = Vec::new();
+    for i in 0..times {
+        let mut line = squares[i % squares.len()].clone();
+        for j in 1..times {
+            for (il, l) in squares[(i + j) % squares.len()].iter().enumerate() {
+                for c in l.iter() {
+                    line[il].push(c.clone());
+                }
+            }
+        }
+        res.append(&mut line);
+    }
+
+    res
+}
+
+pub fn solution1(text: &str) -> ParseResult<usize> {
+    solve(&parse(text)?)
+}
+
+pub fn solution2(text: &str) -> ParseResult<usize> {
+    solve(&mul(parse(text)?, 5, &1, &9))
+}
+
+solution!(15, "Chiton", parse);
+
+#[cfg(test)]
+mod fifteen_tests {
+    use super::{solution1, solution2};
+    use crate::error::ParseError;
+
+    #[test]
+    fn invalid() {
+        assert_eq!(solution1(""), Err(ParseError::new(1, 1, "empty map")));
+        assert_eq!(
+            solution1("19\n10"),
+            Err(ParseError::new(2, 2, "invalid character '0'"))
+        );
+        assert_eq!(
+            solution2("19\n1"),
+            Err(ParseError::new(2, 2, "expected 2 cells, found 1"))
+        );
+    }
+}
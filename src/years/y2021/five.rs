@@ -1,10 +1,9 @@
 use bresenham::Bresenham;
 
-use std::{collections::HashMap, io::BufReader};
+use std::collections::HashMap;
 
-use crate::utils::{is_eof, read_line};
-
-const INPUT: &str = include_str!("../../inputs/5");
+use crate::error::{parse_field, parse_lines, ParseResult};
+use crate::solution::{solution, ParseError};
 
 #[derive(Clone, Copy)]
 struct Point {
@@ -77,11 +76,7 @@ impl Line {
     pub fn is_diagonal(&self) -> bool {
         #[inline]
         fn diff(a: u32, b: u32) -> u32 {
-            if b > a {
-                b - a
-            } else {
-                a - b
-            }
+            b.abs_diff(a)
         }
 
         diff(self.p1.x, self.p2.x) == diff(self.p1.y, self.p2.y)
@@ -106,14 +101,14 @@ impl Canvas {
 
     fn write_point(&mut self, point: (u32, u32)) {
         self.points
-            .insert(point, self.points.get(&point).map(|&v| v).unwrap_or(0) + 1);
+            .insert(point, self.points.get(&point).copied().unwrap_or(0) + 1);
     }
 
     pub fn write<W: Writeable>(&mut self, object: W) {
         object.write(self)
     }
 
-    pub fn points(&self) -> CanvasPoints {
+    pub fn points(&self) -> CanvasPoints<'_> {
         CanvasPoints::new(self)
     }
 }
@@ -166,85 +161,51 @@ impl Writeable for (u32, u32) {
     }
 }
 
-fn parse_point(text: &str) -> Point {
+fn parse_point(i: usize, line: &str, text: &str) -> ParseResult<Point> {
     let c: Vec<&str> = text.split(',').collect();
 
     if c.len() != 2 {
-        panic!("Invalid point")
+        return Err(ParseError::at(i, line, text, "invalid point"));
     }
 
-    Point {
-        x: c[0].trim().parse::<u32>().unwrap(),
-        y: c[1].trim().parse::<u32>().unwrap(),
-    }
+    Ok(Point {
+        x: parse_field(i, line, c[0])?,
+        y: parse_field(i, line, c[1])?,
+    })
 }
 
-fn parse_line(line: &str) -> Line {
+fn parse_line(i: usize, line: &str) -> ParseResult<Line> {
     let points: Vec<&str> = line.split(" -> ").collect();
 
     if points.len() != 2 {
-        panic!("Invalid line")
+        return Err(ParseError::new(i, 1, "invalid line"));
     }
 
-    Line {
-        p1: parse_point(points[0]),
-        p2: parse_point(points[1]),
-    }
+    Ok(Line {
+        p1: parse_point(i, line, points[0])?,
+        p2: parse_point(i, line, points[1])?,
+    })
 }
 
-fn parse(text: &str) -> Vec<Line> {
-    let mut text = BufReader::new(text.as_bytes());
-    let mut lines = Vec::new();
-    while !is_eof(&mut text).unwrap() {
-        lines.push(parse_line(&read_line(&mut text).unwrap()));
-    }
-    lines
+fn parse(text: &str) -> ParseResult<Vec<Line>> {
+    parse_lines(text, parse_line)
 }
 
-fn solve(text: &str, filter: fn(&Line) -> bool) -> usize {
+fn solve(text: &str, filter: fn(&Line) -> bool) -> ParseResult<usize> {
     let mut canvas = Canvas::new();
-    parse(text)
+    parse(text)?
         .into_iter()
         .filter(filter)
         .for_each(|line| canvas.write(line));
-    canvas.points().filter(|(_, x)| *x > 1).count()
+    Ok(canvas.points().filter(|(_, x)| *x > 1).count())
 }
 
-pub(crate) fn solution1(text: &str) -> usize {
+pub fn solution1(text: &str) -> ParseResult<usize> {
     solve(text, Line::is_90deg)
 }
 
-pub(crate) fn solution2(text: &str) -> usize {
+pub fn solution2(text: &str) -> ParseResult<usize> {
     solve(text, |line| line.is_90deg() || line.is_diagonal())
 }
 
-pub fn solution() {
-    println!("Solution 1: {}", solution1(INPUT));
-    println!("Solution 2: {}", solution2(INPUT));
-}
-
-#[cfg(test)]
-mod five_tests {
-    use crate::days::five::{solution1, solution2};
-
-    const INPUT: &str = "0,9 -> 5,9
-8,0 -> 0,8
-9,4 -> 3,4
-2,2 -> 2,1
-7,0 -> 7,4
-6,4 -> 2,0
-0,9 -> 2,9
-3,4 -> 1,4
-0,0 -> 8,8
-5,5 -> 8,2";
-
-    #[test]
-    fn test1() {
-        assert_eq!(solution1(INPUT), 5);
-    }
-
-    #[test]
-    fn test2() {
-        assert_eq!(solution2(INPUT), 12);
-    }
-}
+solution!(5, "Hydrothermal Venture", parse);
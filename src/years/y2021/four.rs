@@ -1,10 +1,7 @@
 #![allow(dead_code)]
 
-use std::io::{BufRead, BufReader};
-
-use crate::utils::{is_eof, read_line};
-
-const INPUT: &str = include_str!("../../inputs/4");
+use crate::error::{parse_field, trim_blank_lines, ParseResult};
+use crate::solution::{solution, ParseError};
 
 #[derive(Copy, Clone, Debug)]
 struct Board {
@@ -14,7 +11,7 @@ struct Board {
 }
 
 impl Board {
-    pub fn new(numbers: &Vec<u8>) -> Option<Self> {
+    pub fn new(numbers: &[u8]) -> Option<Self> {
         if numbers.len() != 25 {
             None
         } else {
@@ -23,9 +20,7 @@ impl Board {
                 markeds: [false; 25],
                 bingo: None,
             };
-            for i in 0..25 {
-                res.numbers[i] = numbers[i];
-            }
+            res.numbers.copy_from_slice(numbers);
             Some(res)
         }
     }
@@ -102,72 +97,81 @@ impl Board {
     }
 }
 
-fn skip_line<R: std::io::Read>(text: &mut BufReader<R>) {
-    if !is_eof(text).unwrap() {
-        let mut _discard = Vec::new();
-        text.read_until(b'\n', &mut _discard).unwrap();
-    }
-}
-
-fn parse_extractions<R: std::io::Read>(text: &mut BufReader<R>) -> Vec<u8> {
-    let line = read_line(text).unwrap();
-    line.split(',').map(|n| n.parse::<u8>().unwrap()).collect()
+fn parse_extractions(line: &str) -> ParseResult<Vec<u8>> {
+    line.split(',').map(|n| parse_field(1, line, n)).collect()
 }
 
-fn parse_board<R: std::io::Read>(text: &mut BufReader<R>) -> Board {
+/// Parses the 5 lines of a board, the first of which is the `first`-th line
+/// of the input.
+fn parse_board(first: usize, lines: &[&str]) -> ParseResult<Board> {
     let mut numbers = Vec::new();
 
-    for _ in 0..5 {
-        let line = read_line(text).unwrap();
-        let mut pieces = line
+    for (i, line) in lines.iter().enumerate() {
+        let row = line
             .split_ascii_whitespace()
-            .map(|p| p.parse::<u8>().unwrap());
-
-        for _ in 0..5 {
-            numbers.push(pieces.next().unwrap());
+            .map(|p| parse_field(first + i, line, p))
+            .collect::<ParseResult<Vec<u8>>>()?;
+
+        if row.len() != 5 {
+            return Err(ParseError::new(
+                first + i,
+                1,
+                format!("expected 5 numbers, found {}", row.len()),
+            ));
         }
 
-        assert_eq!(pieces.next(), None);
+        numbers.extend(row);
     }
 
-    Board::new(&numbers).unwrap()
+    Board::new(&numbers).ok_or_else(|| ParseError::new(first + lines.len(), 1, "incomplete board"))
 }
 
-fn parse(text: &str) -> (Vec<u8>, Vec<Board>) {
-    let mut text = BufReader::new(text.as_bytes());
+fn parse(text: &str) -> ParseResult<(Vec<u8>, Vec<Board>)> {
+    let lines = trim_blank_lines(text).lines().collect::<Vec<_>>();
+    let extractions = parse_extractions(lines.first().copied().unwrap_or_default())?;
     let mut boards = Vec::new();
-    let extractions = parse_extractions(&mut text);
-    while !is_eof(&mut text).unwrap() {
-        skip_line(&mut text);
-        if !is_eof(&mut text).unwrap() {
-            boards.push(parse_board(&mut text));
+
+    // Every board is preceded by an empty line
+    for start in (1..lines.len()).step_by(6) {
+        if !lines[start].trim().is_empty() {
+            return Err(ParseError::new(start + 1, 1, "expected an empty line"));
+        }
+
+        if start + 1 < lines.len() {
+            let end = lines.len().min(start + 6);
+            boards.push(parse_board(start + 2, &lines[start + 1..end])?);
         }
     }
 
-    (extractions, boards)
+    Ok((extractions, boards))
 }
 
-pub(crate) fn solution1(text: &str) -> u32 {
-    let (extractions, mut boards) = parse(text);
+/// The extracted numbers may never complete a board, leaving no winner.
+fn no_bingo() -> ParseError {
+    ParseError::unsolvable("no board ever wins")
+}
+
+pub fn solution1(text: &str) -> ParseResult<u32> {
+    let (extractions, mut boards) = parse(text)?;
 
     for number in extractions {
         for board in boards.iter_mut() {
             board.mark(number);
 
             if board.has_bingo() {
-                return board.unmarkeds().into_iter().map(|x| x as u32).sum::<u32>()
-                    * (number as u32);
+                return Ok(
+                    board.unmarkeds().into_iter().map(|x| x as u32).sum::<u32>() * (number as u32)
+                );
             }
         }
     }
 
-    panic!("no bingo");
+    Err(no_bingo())
 }
 
-pub(crate) fn solution2(text: &str) -> u32 {
-    let (extractions, mut boards) = parse(text);
+pub fn solution2(text: &str) -> ParseResult<u32> {
+    let (extractions, mut boards) = parse(text)?;
     let mut last = None;
-    let mut last_number = None;
 
     for number in extractions {
         let mut i = 0;
@@ -176,58 +180,29 @@ pub(crate) fn solution2(text: &str) -> u32 {
             board.mark(number);
 
             if board.has_bingo() {
-                last = Some(boards.remove(i));
-                last_number = Some(number as u32);
+                last = Some((boards.remove(i), number as u32));
             } else {
                 i += 1;
             }
         }
     }
 
-    last.unwrap()
-        .unmarkeds()
-        .into_iter()
-        .map(|x| x as u32)
-        .sum::<u32>()
-        * last_number.unwrap()
+    let (board, number) = last.ok_or_else(no_bingo)?;
+    Ok(board.unmarkeds().into_iter().map(|x| x as u32).sum::<u32>() * number)
 }
 
-pub fn solution() {
-    println!("Solution 1: {}", solution1(INPUT));
-    println!("Solution 2: {}", solution2(INPUT));
-}
+solution!(4, "Giant Squid", parse);
 
 #[cfg(test)]
 mod four_tests {
-    use crate::days::four::{solution1, solution2};
-
-    const INPUT: &str = "7,4,9,5,11,17,23,2,0,14,21,24,10,16,13,6,15,25,12,22,18,20,8,19,3,26,1
-
-22 13 17 11  0
- 8  2 23  4 24
-21  9 14 16  7
- 6 10  3 18  5
- 1 12 20 15 19
-
- 3 15  0  2 22
- 9 18 13 17  5
-19  8  7 25 23
-20 11 10 24  4
-14 21 16 12  6
-
-14 21 17 24  4
-10 16 15  9 19
-18  8 23 26 20
-22 11 13  6  5
- 2  0 12  3  7";
-
-    #[test]
-    fn test1() {
-        assert_eq!(solution1(INPUT), 4512);
-    }
+    use super::{solution1, solution2};
+    use crate::error::ParseError;
 
     #[test]
-    fn test2() {
-        assert_eq!(solution2(INPUT), 1924);
+    fn no_bingo() {
+        let text = "1,2\n\n1 2 3 4 5\n6 7 8 9 10\n11 12 13 14 15\n16 17 18 19 20\n21 22 23 24 25";
+        let err = Err(ParseError::unsolvable("no board ever wins"));
+        assert_eq!(solution1(text), err);
+        assert_eq!(solution2(text), err);
     }
 }
@@ -1,9 +1,9 @@
 use std::{collections::BTreeMap, mem::take};
 
+use crate::error::{trim_blank_lines, ParseResult};
+use crate::solution::{solution, ParseError};
 use crate::utils::min_max;
 
-const INPUT: &str = include_str!("../../inputs/14");
-
 #[derive(Debug)]
 struct Polymer {
     units: BTreeMap<(char, char), usize>,
@@ -70,7 +70,7 @@ impl Polymer {
         let counts = self.get_counts();
         let (min, max) = min_max(counts.values());
 
-        (min.map(|x| *x), max.map(|x| *x))
+        (min.copied(), max.copied())
     }
 
     pub fn result(&self) -> usize {
@@ -80,89 +80,59 @@ impl Polymer {
     }
 }
 
-fn parse_rule(text: &str) -> ((char, char), char) {
-    let mut it = text.splitn(2, " -> ");
-    let pattern = it.next().unwrap();
-    let insertion = it.next().unwrap();
-
-    let pattern = pattern.chars().collect::<Vec<_>>();
-    let insertion = insertion.chars().collect::<Vec<_>>();
-
-    assert_eq!(it.next(), None);
-    assert_eq!(pattern.len(), 2);
-    assert_eq!(insertion.len(), 1);
-
-    ((pattern[0], pattern[1]), insertion[0])
+fn parse_rule(i: usize, text: &str) -> ParseResult<((char, char), char)> {
+    let (pattern, insertion) = text
+        .split_once(" -> ")
+        .ok_or_else(|| ParseError::new(i, 1, "expected a '->' separated rule"))?;
+
+    let chars = |text: &str| text.chars().collect::<Vec<_>>();
+
+    match (&chars(pattern)[..], &chars(insertion)[..]) {
+        (&[a, b], &[c]) => Ok(((a, b), c)),
+        ([_, _], _) => Err(ParseError::at(
+            i,
+            text,
+            insertion,
+            "expected a single element",
+        )),
+        _ => Err(ParseError::new(i, 1, "expected a pair of elements")),
+    }
 }
 
-fn parse(text: &str) -> Polymer {
-    let mut lines = text.lines().map(str::trim);
+fn parse(text: &str) -> ParseResult<Polymer> {
+    let mut lines = trim_blank_lines(text).lines().map(str::trim).enumerate();
 
-    let template = lines.next().unwrap();
+    let template = lines.next().map(|(_, line)| line).unwrap_or_default();
 
-    assert_eq!(lines.next(), Some(""));
+    match lines.next() {
+        Some((_, "")) => (),
+        Some((i, _)) => return Err(ParseError::new(i + 1, 1, "expected an empty line")),
+        None => return Err(ParseError::new(2, 1, "missing insertion rules")),
+    }
 
-    let rules = lines
-        .map(|line| parse_rule(line))
-        .fold(BTreeMap::new(), |mut acc, (k, v)| {
-            acc.insert(k, v);
-            acc
-        });
+    let mut rules = BTreeMap::new();
+    for (i, line) in lines {
+        let (k, v) = parse_rule(i + 1, line)?;
+        rules.insert(k, v);
+    }
 
-    Polymer::new(template, rules)
+    Ok(Polymer::new(template, rules))
 }
 
-fn run(text: &str, steps: usize) -> usize {
-    let mut polymer = parse(text);
+fn run(text: &str, steps: usize) -> ParseResult<usize> {
+    let mut polymer = parse(text)?;
     for _ in 0..steps {
         polymer.evolve();
     }
-    polymer.result()
+    Ok(polymer.result())
 }
 
-pub(crate) fn solution1(text: &str) -> usize {
+pub fn solution1(text: &str) -> ParseResult<usize> {
     run(text, 10)
 }
 
-pub(crate) fn solution2(text: &str) -> usize {
+pub fn solution2(text: &str) -> ParseResult<usize> {
     run(text, 40)
 }
 
-pub fn solution() {
-    println!("Solution 1: {}", solution1(INPUT));
-    println!("Solution 2: {}", solution2(INPUT));
-}
-
-#[cfg(test)]
-mod fourteen_tests {
-    use super::{solution1, solution2};
-
-    const INPUT: &str = "NNCB
-
-CH -> B
-HH -> N
-CB -> H
-NH -> C
-HB -> C
-HC -> B
-HN -> C
-NN -> C
-BH -> H
-NC -> B
-NB -> B
-BN -> B
-BB -> N
-BC -> B
-CC -> N
-CN -> C";
-
-    #[test]
-    fn test1() {
-        assert_eq!(solution1(INPUT), 1588);
-    }
-
-    #[test]
-    fn test2() {
-        assert_eq!(solution2(INPUT), 2188189693529);
-    }
-}
+solution!(14, "Extended Polymerization", parse);
@@ -0,0 +1,55 @@
+pub mod eight;
+pub mod eighteen;
+pub mod eleven;
+pub mod fifteen;
+pub mod five;
+pub mod four;
+pub mod fourteen;
+pub mod nine;
+pub mod nineteen;
+pub mod one;
+pub mod seven;
+pub mod seventeen;
+pub mod six;
+pub mod sixteen;
+pub mod ten;
+pub mod thirteen;
+pub mod three;
+pub mod twelve;
+pub mod twenty;
+pub mod twentyfive;
+pub mod twentyfour;
+pub mod twentyone;
+pub mod twentythree;
+pub mod twentytwo;
+pub mod two;
+
+use crate::solution::Solution;
+
+pub const DAYS: &[&dyn Solution] = &[
+    &one::Puzzle,
+    &two::Puzzle,
+    &three::Puzzle,
+    &four::Puzzle,
+    &five::Puzzle,
+    &six::Puzzle,
+    &seven::Puzzle,
+    &eight::Puzzle,
+    &nine::Puzzle,
+    &ten::Puzzle,
+    &eleven::Puzzle,
+    &twelve::Puzzle,
+    &thirteen::Puzzle,
+    &fourteen::Puzzle,
+    &fifteen::Puzzle,
+    &sixteen::Puzzle,
+    &seventeen::Puzzle,
+    &eighteen::Puzzle,
+    &nineteen::Puzzle,
+    &twenty::Puzzle,
+    &twentyone::Puzzle,
+    &twentytwo::Puzzle,
+    &twentythree::Puzzle,
+    &twentyfour::Puzzle,
+    &twentyfive::Puzzle,
+];
@@ -0,0 +1,47 @@
+use crate::error::{parse_chars, ParseResult};
+use crate::solution::solution;
+use crate::utils::matrix::{
+    cardinal_coords, components, enum_iter, enum_navigate, Component, Coord, IndexesIterator,
+    Matrix, Neighbors,
+};
+
+fn parse(text: &str) -> ParseResult<Matrix<u8>> {
+    parse_chars(text, |c| c.to_digit(10).map(|d| d as u8))
+}
+
+#[inline]
+fn adiacents<'a, T>(
+    matrix: &'a Matrix<T>,
+    pos: Coord,
+) -> IndexesIterator<'a, Matrix<T>, Neighbors<4>> {
+    enum_navigate(matrix, &pos, cardinal_coords)
+}
+
+fn low_points<'a, T: PartialOrd>(
+    matrix: &'a Matrix<T>,
+) -> impl Iterator<Item = (Coord, &'a T)> + 'a {
+    enum_iter(matrix).filter(|&(pos, v)| adiacents(matrix, pos).find(|&(_, v2)| v >= v2).is_none())
+}
+
+/// Basins are delimited by the highest locations, which belong to none.
+fn basins<T: PartialOrd>(matrix: &Matrix<T>, max: T) -> Vec<Component> {
+    components(matrix, cardinal_coords, |(_, value)| *value < max).components
+}
+
+pub fn solution1(text: &str) -> ParseResult<usize> {
+    Ok(low_points(&parse(text)?)
+        .map(|(_, &x)| 1 + (x as usize))
+        .sum::<usize>())
+}
+
+pub fn solution2(text: &str) -> ParseResult<usize> {
+    let mut basins_sizes = basins(&parse(text)?, 9)
+        .iter()
+        .map(|basin| basin.size)
+        .collect::<Vec<_>>();
+    basins_sizes.sort_by(|a, b| b.cmp(a));
+
+    Ok(basins_sizes.iter().take(3).product())
+}
+
+solution!(9, "Smoke Basin", parse);
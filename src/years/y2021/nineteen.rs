@@ -1,11 +1,12 @@
 use std::collections::{BTreeMap, BTreeSet};
 
-const INPUT: &str = include_str!("../../inputs/19");
+use crate::error::{parse_field, ParseResult};
+use crate::solution::{solution, ParseError};
 
 type N = i64;
 type Coord = (N, N, N);
 
-const ROTATIONS: &'static [(Coord, Coord, Coord)] = &[
+const ROTATIONS: &[(Coord, Coord, Coord)] = &[
     ((-1, 0, 0), (0, -1, 0), (0, 0, 1)),
     ((-1, 0, 0), (0, 0, -1), (0, -1, 0)),
     ((-1, 0, 0), (0, 0, 1), (0, 1, 0)),
@@ -59,7 +60,7 @@ impl Scanner {
 
     pub fn alt_orientations<'a>(&'a self) -> ScannerAltOrientationsIterator<'a> {
         ScannerAltOrientationsIterator {
-            scanner: &self,
+            scanner: self,
             rotation: 0,
         }
     }
@@ -87,31 +88,62 @@ impl<'a> Iterator for ScannerAltOrientationsIterator<'a> {
     }
 }
 
-fn parse_scanner(text: &str) -> Scanner {
-    let text = text.trim();
-    let mut lines = text.lines();
-    let first = &lines.next().unwrap()[12..];
-    let num: usize = first[..(first.find(' ').unwrap())].parse().unwrap();
-    let coords = lines
-        .map(|line| {
-            let mut p = line.split(',').map(|n| n.parse::<N>().unwrap());
-            (p.next().unwrap(), p.next().unwrap(), p.next().unwrap())
-        })
-        .collect();
-
-    Scanner::new(num, coords)
+fn parse_header(i: usize, line: &str) -> ParseResult<usize> {
+    let num = line
+        .trim()
+        .strip_prefix("--- scanner ")
+        .and_then(|rest| rest.strip_suffix(" ---"))
+        .ok_or_else(|| ParseError::new(i, 1, "expected a '--- scanner <N> ---' header"))?;
+
+    parse_field(i, line, num)
+}
+
+fn parse_coord(i: usize, line: &str) -> ParseResult<Coord> {
+    let p = line
+        .split(',')
+        .map(|n| parse_field(i, line, n))
+        .collect::<ParseResult<Vec<N>>>()?;
+
+    match p[..] {
+        [x, y, z] => Ok((x, y, z)),
+        _ => Err(ParseError::new(
+            i,
+            1,
+            format!("expected 3 coordinates, found {}", p.len()),
+        )),
+    }
 }
 
-fn parse(text: &str) -> BTreeMap<usize, Scanner> {
-    text.split("\n\n")
-        .map(parse_scanner)
-        .fold(BTreeMap::new(), |mut acc, scanner| {
-            acc.insert(scanner.num(), scanner);
-            acc
-        })
+fn parse(text: &str) -> ParseResult<BTreeMap<usize, Scanner>> {
+    let mut scanners = BTreeMap::new();
+    let mut current: Option<(usize, BTreeSet<Coord>)> = None;
+
+    // Every scanner is a header followed by its coordinates, up to an empty line
+    for (i, line) in text.lines().enumerate() {
+        if line.trim().is_empty() {
+            if let Some((num, coords)) = current.take() {
+                scanners.insert(num, Scanner::new(num, coords));
+            }
+        } else if let Some((_, coords)) = current.as_mut() {
+            coords.insert(parse_coord(i + 1, line)?);
+        } else {
+            current = Some((parse_header(i + 1, line)?, BTreeSet::new()));
+        }
+    }
+
+    if let Some((num, coords)) = current {
+        scanners.insert(num, Scanner::new(num, coords));
+    }
+
+    Ok(scanners)
 }
 
-fn match_scanner(known: &BTreeSet<Coord>, scanners: &BTreeMap<usize, Scanner>) -> (Scanner, Coord) {
+/// A scanner sharing at least 12 beacons with `known`, moved into its frame,
+/// along with its position.
+fn match_scanner(
+    known: &BTreeSet<Coord>,
+    scanners: &BTreeMap<usize, Scanner>,
+) -> Option<(Scanner, Coord)> {
     for s in scanners.values() {
         for o in s.alt_orientations() {
             for base_c in known {
@@ -138,36 +170,42 @@ fn match_scanner(known: &BTreeSet<Coord>, scanners: &BTreeMap<usize, Scanner>) -
                             .map(|&c| (c.0 + off.0, c.1 + off.1, c.2 + off.2))
                             .collect::<BTreeSet<_>>();
 
-                        return (Scanner::new(o.num(), coords), off);
+                        return Some((Scanner::new(o.num(), coords), off));
                     }
                 }
             }
         }
     }
 
-    unreachable!()
+    None
 }
 
-fn solve(text: &str) -> (BTreeSet<Coord>, BTreeSet<Coord>) {
-    let mut scanners = parse(text);
+fn solve(text: &str) -> ParseResult<(BTreeSet<Coord>, BTreeSet<Coord>)> {
+    let mut scanners = parse(text)?;
     let Scanner {
         coords: mut known, ..
-    } = scanners.remove(&0).unwrap();
+    } = scanners
+        .remove(&0)
+        .ok_or_else(|| ParseError::new(1, 1, "missing scanner 0"))?;
     let mut offsets = BTreeSet::new();
     offsets.insert((0, 0, 0));
 
     while !scanners.is_empty() {
-        let (Scanner { mut coords, num }, offset) = match_scanner(&known, &scanners);
+        let (Scanner { mut coords, num }, offset) =
+            match_scanner(&known, &scanners).ok_or_else(|| {
+                let nums = scanners.keys().map(ToString::to_string).collect::<Vec<_>>();
+                ParseError::unsolvable(format!("cannot place scanners {}", nums.join(", ")))
+            })?;
         scanners.remove(&num);
         known.append(&mut coords);
         offsets.insert(offset);
     }
 
-    (known, offsets)
+    Ok((known, offsets))
 }
 
 fn manhattan_distance(a: &Coord, b: &Coord) -> u64 {
-    ((a.0 - b.0).abs() as u64) + ((a.1 - b.1).abs() as u64) + ((a.2 - b.2).abs() as u64)
+    (a.0 - b.0).unsigned_abs() + (a.1 - b.1).unsigned_abs() + (a.2 - b.2).unsigned_abs()
 }
 
 fn result1(solution: &(BTreeSet<Coord>, BTreeSet<Coord>)) -> usize {
@@ -187,14 +225,26 @@ fn result2(solution: &(BTreeSet<Coord>, BTreeSet<Coord>)) -> u64 {
     max
 }
 
-pub fn solution() {
-    let res = solve(INPUT);
+pub fn solution1(text: &str) -> ParseResult<usize> {
+    Ok(result1(&solve(text)?))
+}
 
-    println!("Solution 1: {}", result1(&res));
-    println!("Solution 2: {}", result2(&res));
+pub fn solution2(text: &str) -> ParseResult<u64> {
+    Ok(result2(&solve(text)?))
 }
 
+solution!(19, "Beacon Scanner", parse);
+
 #[cfg(test)]
 mod nineteen_tests {
-    // No tests for you this time
+    use super::solution1;
+    use crate::error::ParseError;
+
+    #[test]
+    fn disjoint() {
+        assert_eq!(
+            solution1("--- scanner 0 ---\n0,0,0\n\n--- scanner 1 ---\n1,2,3"),
+            Err(ParseError::unsolvable("cannot place scanners 1"))
+        );
+    }
 }
@@ -0,0 +1,35 @@
+use crate::error::{parse_field, trim_blank_lines, ParseResult};
+use crate::solution::solution;
+use crate::window::{Sum, Window};
+
+pub fn window_cmp(text: &str, window_size: usize) -> ParseResult<usize> {
+    let mut window: Window<u32, Sum<u32>> = Window::new(window_size);
+    let mut prev = None;
+    let mut count: usize = 0;
+
+    for (i, line) in trim_blank_lines(text).lines().enumerate() {
+        window.push(parse_field(i + 1, line, line)?);
+
+        if window.is_full() {
+            let sum = window.aggregate();
+
+            if prev.is_some() && sum > prev.unwrap() {
+                count += 1;
+            }
+
+            prev = Some(sum);
+        }
+    }
+
+    Ok(count)
+}
+
+pub fn solution1(text: &str) -> ParseResult<usize> {
+    window_cmp(text, 1)
+}
+
+pub fn solution2(text: &str) -> ParseResult<usize> {
+    window_cmp(text, 3)
+}
+
+solution!(1, "Sonar Sweep");
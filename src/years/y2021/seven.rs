@@ -1,19 +1,14 @@
-const INPUT: &str = include_str!("../../inputs/7");
+use crate::error::{parse_field, ParseResult};
+use crate::solution::solution;
 
-fn parse(text: &str) -> Vec<usize> {
-    text.trim()
-        .split(',')
-        .map(|x| x.parse::<usize>().unwrap())
-        .collect()
+fn parse(text: &str) -> ParseResult<Vec<usize>> {
+    let line = text.lines().next().unwrap_or_default();
+    line.split(',').map(|x| parse_field(1, line, x)).collect()
 }
 
 #[inline]
 fn diff(a: usize, b: usize) -> usize {
-    if b > a {
-        b - a
-    } else {
-        a - b
-    }
+    b.abs_diff(a)
 }
 
 fn linear_calculate_fuel(positions: &Vec<usize>, position: usize) -> usize {
@@ -38,42 +33,32 @@ fn sum_calculate_fuel(positions: &Vec<usize>, position: usize) -> usize {
     fuel
 }
 
-fn solve(text: &str, calculate_fuel: fn(&Vec<usize>, usize) -> usize) -> usize {
-    let positions = parse(text);
-    let max = *positions.iter().max().unwrap();
-    (0..max)
-        .into_iter()
+fn solve(text: &str, calculate_fuel: fn(&Vec<usize>, usize) -> usize) -> ParseResult<usize> {
+    let positions = parse(text)?;
+    let max = positions.iter().copied().max().unwrap_or(0);
+    Ok((0..=max)
         .map(|i| calculate_fuel(&positions, i))
         .min()
-        .unwrap()
+        .unwrap_or(0))
 }
 
-pub(crate) fn solution1(text: &str) -> usize {
+pub fn solution1(text: &str) -> ParseResult<usize> {
     solve(text, linear_calculate_fuel)
 }
 
-pub(crate) fn solution2(text: &str) -> usize {
+pub fn solution2(text: &str) -> ParseResult<usize> {
     solve(text, sum_calculate_fuel)
 }
 
-pub fn solution() {
-    println!("Solution 1: {}", solution1(INPUT));
-    println!("Solution 2: {}", solution2(INPUT));
-}
+solution!(7, "The Treachery of Whales", parse);
 
 #[cfg(test)]
 mod seven_tests {
     use super::{solution1, solution2};
 
-    const INPUT: &str = "16,1,2,0,4,2,7,1,2,14";
-
-    #[test]
-    fn test1() {
-        assert_eq!(solution1(INPUT), 37);
-    }
-
     #[test]
-    fn test2() {
-        assert_eq!(solution2(INPUT), 168);
+    fn aligned() {
+        assert_eq!(solution1("0,0"), Ok(0));
+        assert_eq!(solution2("3"), Ok(0));
     }
 }
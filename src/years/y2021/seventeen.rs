@@ -0,0 +1,136 @@
+use crate::error::{parse_field, ParseResult};
+use crate::solution::{solution, ParseError};
+
+type N = i64;
+type Coord = (N, N);
+type Square = (Coord, Coord);
+type Velocity = (N, N);
+
+fn parse_range(line: &str, text: &str) -> ParseResult<(N, N)> {
+    let (start, stop) = text
+        .split_once("..")
+        .ok_or_else(|| ParseError::at(1, line, text, "expected a '..' separated range"))?;
+    let start: N = parse_field(1, line, start)?;
+    let stop: N = parse_field(1, line, stop)?;
+
+    if start > stop {
+        Ok((stop, start))
+    } else {
+        Ok((start, stop))
+    }
+}
+
+fn parse(text: &str) -> ParseResult<Square> {
+    let line = text.lines().next().unwrap_or_default();
+    let ranges = line
+        .trim()
+        .strip_prefix("target area: ")
+        .ok_or_else(|| ParseError::new(1, 1, "expected 'target area: '"))?;
+    let (first, second) = ranges
+        .split_once(',')
+        .ok_or_else(|| ParseError::at(1, line, ranges, "expected two ',' separated ranges"))?;
+    let (first, second) = (first.trim(), second.trim());
+
+    let ((x1, x2), (y1, y2)) = match (first.split_at_checked(2), second.split_at_checked(2)) {
+        (Some(("x=", x)), Some(("y=", y))) | (Some(("y=", y)), Some(("x=", x))) => {
+            (parse_range(line, x)?, parse_range(line, y)?)
+        }
+        _ => {
+            return Err(ParseError::at(
+                1,
+                line,
+                first,
+                "expected an x range and a y range",
+            ))
+        }
+    };
+
+    // Trajectories are only searched forward and down
+    if x1 <= 0 || y2 >= 0 {
+        return Err(ParseError::at(
+            1,
+            line,
+            ranges,
+            "expected a target ahead of and below the launch point",
+        ));
+    }
+
+    Ok(((x1, y2), (x2, y1)))
+}
+
+/// The highest point of the trajectory, starting from the launch point, when it
+/// hits the target.
+fn hits_target(square: &Square, mut velocity: Velocity) -> Option<N> {
+    let mut max: N = 0;
+
+    let (mut x, mut y) = velocity;
+
+    while x <= square.1 .0 && y >= square.1 .1 {
+        max = max.max(y);
+
+        if square.0 .0 <= x && x <= square.1 .0 && square.0 .1 >= y && y >= square.1 .1 {
+            return Some(max);
+        }
+
+        velocity.1 -= 1;
+        if velocity.0 > 0 {
+            velocity.0 -= 1;
+        }
+        x += velocity.0;
+        y += velocity.1;
+    }
+
+    None
+}
+
+/// The highest point of every trajectory hitting the target. Any faster
+/// towards the far edge, the probe overshoots it on the first step. Shot up at
+/// `vy`, it comes back to 0 at `-vy - 1`, which must not be below the bottom
+/// edge either.
+fn hits(square: &Square) -> impl Iterator<Item = N> + '_ {
+    let bottom = square.1 .1;
+    (1..=square.1 .0)
+        .flat_map(move |x| (bottom..-bottom).filter_map(move |y| hits_target(square, (x, y))))
+}
+
+pub fn solution1(text: &str) -> ParseResult<N> {
+    let square = parse(text)?;
+    hits(&square)
+        .max()
+        .ok_or_else(|| ParseError::unsolvable("no trajectory hits the target"))
+}
+
+pub fn solution2(text: &str) -> ParseResult<usize> {
+    Ok(hits(&parse(text)?).count())
+}
+
+solution!(17, "Trick Shot", parse);
+
+#[cfg(test)]
+mod seventeen_tests {
+    use super::{solution1, solution2};
+    use crate::error::ParseError;
+
+    #[test]
+    fn invalid() {
+        assert_eq!(
+            solution1("target area: x=20..30, y=-10..-x"),
+            Err(ParseError::new(1, 31, "invalid value '-x'"))
+        );
+        assert_eq!(
+            solution1("target area: x=20..30"),
+            Err(ParseError::new(1, 14, "expected two ',' separated ranges"))
+        );
+        assert_eq!(
+            solution1("target area: x=-30..-20, y=-10..-5"),
+            Err(ParseError::new(
+                1,
+                14,
+                "expected a target ahead of and below the launch point"
+            ))
+        );
+        // No x velocity stops the probe within 4..=5
+        assert_eq!(solution1("target area: x=4..5, y=-3..-2"), Ok(0));
+        assert_eq!(solution2("target area: x=4..5, y=-3..-2"), Ok(5));
+    }
+}
@@ -0,0 +1,42 @@
+use crate::error::{parse_field, ParseResult};
+use crate::solution::{solution, ParseError};
+use crate::utils::ring::FixedRingBuffer;
+
+fn parse(text: &str) -> ParseResult<FixedRingBuffer<usize>> {
+    let mut fishes = FixedRingBuffer::new(9);
+    let line = text.lines().next().unwrap_or_default();
+    for x in line.split(',') {
+        let timer: usize = parse_field(1, line, x)?;
+        if timer >= fishes.len() {
+            return Err(ParseError::at(
+                1,
+                line,
+                x,
+                format!("invalid timer {}", timer),
+            ));
+        }
+        fishes[timer] += 1;
+    }
+    Ok(fishes)
+}
+
+fn evolve(fishes: &mut FixedRingBuffer<usize>) {
+    fishes.forth();
+    fishes[6] += fishes[8];
+}
+
+fn solve(text: &str, days: usize) -> ParseResult<usize> {
+    let mut fishes = parse(text)?;
+    (0..days).for_each(|_| evolve(&mut fishes));
+    Ok(fishes.into_iter().sum())
+}
+
+pub fn solution1(text: &str) -> ParseResult<usize> {
+    solve(text, 80)
+}
+
+pub fn solution2(text: &str) -> ParseResult<usize> {
+    solve(text, 256)
+}
+
+solution!(6, "Lanternfish", parse);
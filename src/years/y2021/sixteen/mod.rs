@@ -2,12 +2,13 @@ use bitbuffer::BigEndian as NetworkEndian;
 
 use bitbuffer::{BitError, BitRead, BitReadBuffer, BitReadStream, Endianness, Result};
 
-pub(crate) mod packets;
+use crate::error::ParseResult;
+use crate::solution::{solution, ParseError};
 
-const INPUT: &str = include_str!("../../../inputs/16");
+pub mod packets;
 
 #[derive(Clone, Debug)]
-pub(crate) enum RawPacket {
+pub enum RawPacket {
     Operation {
         version: u8,
         _type: u8,
@@ -24,11 +25,7 @@ fn read_header<'a, E: Endianness>(stream: &mut BitReadStream<'a, E>) -> Result<(
 }
 
 fn read_literal_is_last<'a, E: Endianness>(stream: &mut BitReadStream<'a, E>) -> Result<bool> {
-    Ok(if stream.read_int::<u8>(1)? == 1 {
-        false
-    } else {
-        true
-    })
+    Ok(stream.read_int::<u8>(1)? != 1)
 }
 
 fn read_literal<'a, E: Endianness>(stream: &mut BitReadStream<'a, E>) -> Result<u64> {
@@ -152,16 +149,29 @@ impl<'a, E: Endianness> BitRead<'a, E> for RawPacket {
     }
 }
 
-pub(crate) fn parse(text: &str) -> Vec<RawPacket> {
-    let ints = text
-        .trim()
-        .as_bytes()
+pub fn parse(text: &str) -> ParseResult<Vec<RawPacket>> {
+    let digits = text
+        .lines()
+        .next()
+        .unwrap_or_default()
+        .trim_end()
+        .chars()
+        .enumerate()
+        .map(|(i, c)| {
+            c.to_digit(16)
+                .map(|d| d as u8)
+                .ok_or_else(|| ParseError::new(1, i + 1, format!("invalid hex digit '{}'", c)))
+        })
+        .collect::<ParseResult<Vec<u8>>>()?;
+    let ints = digits
         .chunks(2)
-        .map(|x| u8::from_str_radix(unsafe { std::str::from_utf8_unchecked(x) }, 16).unwrap())
+        .map(|x| (x[0] << 4) | x.get(1).copied().unwrap_or(0))
         .collect::<Vec<u8>>();
     let buffer = BitReadBuffer::new(&ints, NetworkEndian);
     let mut stream = BitReadStream::new(buffer);
-    read_all_packets(&mut stream).unwrap()
+    // Every hex digit holds 4 bits of the stream
+    read_all_packets(&mut stream)
+        .map_err(|err| ParseError::new(1, stream.pos() / 4 + 1, format!("invalid packet: {}", err)))
 }
 
 fn sum_raw_packet_versions(packet: &RawPacket) -> usize {
@@ -172,16 +182,16 @@ fn sum_raw_packet_versions(packet: &RawPacket) -> usize {
             .unwrap_or(0)
 }
 
-pub(crate) fn sum_raw_packets_versions(packets: &Vec<RawPacket>) -> usize {
+pub fn sum_raw_packets_versions(packets: &[RawPacket]) -> usize {
     packets.iter().map(sum_raw_packet_versions).sum()
 }
 
-pub(crate) fn solution1(text: &str) -> usize {
-    sum_raw_packets_versions(&parse(text))
+pub fn solution1(text: &str) -> ParseResult<usize> {
+    Ok(sum_raw_packets_versions(&parse(text)?))
 }
 
 #[derive(Clone, Debug)]
-pub(crate) enum Packet {
+pub enum Packet {
     Sum(self::packets::Sum),
     Product(self::packets::Product),
     Minimum(self::packets::Minimum),
@@ -241,196 +251,82 @@ impl TryFrom<RawPacket> for Packet {
     }
 }
 
-pub(crate) trait Operation {
+pub trait Operation {
     fn version(&self) -> u8;
 
     fn eval(&self) -> u64;
 }
 
-pub(crate) fn eval(text: &str) -> u64 {
-    let packet: Packet = parse(text).remove(0).try_into().unwrap();
-    packet.eval()
+pub fn eval(text: &str) -> ParseResult<u64> {
+    let packet: Packet = parse(text)?
+        .into_iter()
+        .next()
+        .ok_or_else(|| ParseError::new(1, 1, "missing packet"))?
+        .try_into()
+        .map_err(|_| ParseError::new(1, 1, "invalid operation packet"))?;
+    Ok(packet.eval())
 }
 
-pub(crate) fn solution2(text: &str) -> u64 {
+pub fn solution2(text: &str) -> ParseResult<u64> {
     eval(text)
 }
 
-pub fn solution() {
-    println!("Solution 1: {}", solution1(INPUT));
-    println!("Solution 2: {}", solution2(INPUT));
-}
+solution!(16, "Packet Decoder", parse);
 
 #[cfg(test)]
 mod sixteen_tests {
-    use super::{eval, parse, sum_raw_packets_versions};
-
-    #[test]
-    fn test1() {
-        let packets = parse("D2FE28");
-        assert_eq!(packets.len(), 1);
-        let packet = &packets[0];
-        assert!(packet.is_literal());
-        assert_eq!(packet.version(), 6);
-        assert_eq!(packet.literal(), Some(2021));
-    }
-
-    #[test]
-    fn test2() {
-        let packets = parse("38006F45291200");
-        assert_eq!(packets.len(), 1);
-        let packet = &packets[0];
-        assert!(packet.is_operation());
-        assert_eq!(packet.version(), 1);
-        assert_eq!(packet.get_type(), 6);
-        assert_eq!(packet.children().map(|c| c.len()), Some(2));
-        let first = &packet.children().unwrap()[0];
-        assert!(first.is_literal());
-        assert_eq!(first.version(), 6);
-        assert_eq!(first.literal(), Some(10));
-        let second = &packet.children().unwrap()[1];
-        assert!(second.is_literal());
-        assert_eq!(second.version(), 2);
-        assert_eq!(second.literal(), Some(20));
-    }
-
-    #[test]
-    fn test3() {
-        let packets = parse("EE00D40C823060");
-        assert_eq!(packets.len(), 1);
-        let packet = &packets[0];
-        assert!(packet.is_operation());
-        assert_eq!(packet.version(), 7);
-        assert_eq!(packet.get_type(), 3);
-        assert_eq!(packet.children().map(|x| x.len()), Some(3));
-        let first = &packet.children().unwrap()[0];
-        assert!(first.is_literal());
-        assert_eq!(first.literal(), Some(1));
-        let second = &packet.children().unwrap()[1];
-        assert!(second.is_literal());
-        assert_eq!(second.literal(), Some(2));
-        let third = &packet.children().unwrap()[2];
-        assert!(third.is_literal());
-        assert_eq!(third.literal(), Some(3));
-    }
-
-    #[test]
-    fn test4() {
-        let packets = parse("8A004A801A8002F478");
-        assert_eq!(packets.len(), 1);
-        let packet = &packets[0];
-        assert!(packet.is_operation());
-        assert_eq!(packet.version(), 4);
-        assert_eq!(packet.children().map(|c| c.len()), Some(1));
-        let packet = &packet.children().unwrap()[0];
-        assert!(packet.is_operation());
-        assert_eq!(packet.version(), 1);
-        assert_eq!(packet.children().map(|c| c.len()), Some(1));
-        let packet = &packet.children().unwrap()[0];
-        assert!(packet.is_operation());
-        assert_eq!(packet.version(), 5);
-        assert_eq!(packet.children().map(|c| c.len()), Some(1));
-        let packet = &packet.children().unwrap()[0];
-        assert!(packet.is_literal());
-        assert_eq!(packet.version(), 6);
-        assert_eq!(sum_raw_packets_versions(&packets), 16);
-    }
-
-    #[test]
-    fn test5() {
-        let packets = parse("620080001611562C8802118E34");
-        assert_eq!(packets.len(), 1);
-        let packet = &packets[0];
-        assert!(packet.is_operation());
-        assert_eq!(packet.children().map(|c| c.len()), Some(2));
-        for packet in packet.children().unwrap() {
-            assert!(packet.is_operation());
-            assert_eq!(packet.children().map(|c| c.len()), Some(2));
-            for packet in packet.children().unwrap() {
-                assert!(packet.is_literal());
-            }
-        }
-        assert_eq!(sum_raw_packets_versions(&packets), 12);
-    }
-
-    #[test]
-    fn test6() {
-        let packets = parse("C0015000016115A2E0802F182340");
-        assert_eq!(packets.len(), 1);
-        let packet = &packets[0];
-        assert!(packet.is_operation());
-        assert_eq!(packet.children().map(|c| c.len()), Some(2));
-        for packet in packet.children().unwrap() {
-            assert!(packet.is_operation());
-            assert_eq!(packet.children().map(|c| c.len()), Some(2));
-            for packet in packet.children().unwrap() {
-                assert!(packet.is_literal());
+    use super::{parse, RawPacket};
+    use crate::error::ParseError;
+
+    /// A packet tree written as `<version>:<literal>` for literals and as
+    /// `<version>:<type>(<children>)` for operations.
+    fn tree(packet: &RawPacket) -> String {
+        match packet.children() {
+            Some(children) => {
+                let children = children.iter().map(tree).collect::<Vec<_>>();
+                format!(
+                    "{}:{}({})",
+                    packet.version(),
+                    packet.get_type(),
+                    children.join(",")
+                )
             }
+            None => format!("{}:{}", packet.version(), packet.literal().unwrap()),
         }
-        assert_eq!(sum_raw_packets_versions(&packets), 23);
     }
 
     #[test]
-    fn test7() {
-        let packets = parse("A0016C880162017C3686B18A3D4780");
-        assert_eq!(packets.len(), 1);
-
-        let packet = &packets[0];
-        assert!(packet.is_operation());
-        assert_eq!(packet.children().map(|c| c.len()), Some(1));
-
-        let packet = &packet.children().unwrap()[0];
-        assert!(packet.is_operation());
-        assert_eq!(packet.children().map(|c| c.len()), Some(1));
-
-        let packet = &packet.children().unwrap()[0];
-        assert!(packet.is_operation());
-        assert_eq!(packet.children().map(|c| c.len()), Some(5));
-
-        for packet in packet.children().unwrap() {
-            assert!(packet.is_literal());
+    fn trees() {
+        let trees = [
+            ("D2FE28", "6:2021"),
+            ("38006F45291200", "1:6(6:10,2:20)"),
+            ("EE00D40C823060", "7:3(2:1,4:2,1:3)"),
+            ("8A004A801A8002F478", "4:2(1:2(5:2(6:15)))"),
+            (
+                "620080001611562C8802118E34",
+                "3:0(0:0(0:10,5:11),1:0(0:12,3:13))",
+            ),
+            (
+                "C0015000016115A2E0802F182340",
+                "6:0(0:0(0:10,6:11),4:0(7:12,0:13))",
+            ),
+            (
+                "A0016C880162017C3686B18A3D4780",
+                "5:0(1:0(3:0(7:6,6:6,5:12,2:15,2:15)))",
+            ),
+        ];
+        for (text, expected) in trees {
+            let packets = parse(text).unwrap();
+            assert_eq!(packets.iter().map(tree).collect::<Vec<_>>(), [expected]);
         }
-
-        assert_eq!(sum_raw_packets_versions(&packets), 31);
-    }
-
-    #[test]
-    fn test8() {
-        assert_eq!(eval("C200B40A82"), 3);
-    }
-
-    #[test]
-    fn test9() {
-        assert_eq!(eval("04005AC33890"), 54);
-    }
-
-    #[test]
-    fn test10() {
-        assert_eq!(eval("880086C3E88112"), 7);
-    }
-
-    #[test]
-    fn test11() {
-        assert_eq!(eval("CE00C43D881120"), 9);
-    }
-
-    #[test]
-    fn test12() {
-        assert_eq!(eval("D8005AC2A8F0"), 1);
-    }
-
-    #[test]
-    fn test13() {
-        assert_eq!(eval("F600BC2D8F"), 0);
-    }
-
-    #[test]
-    fn test14() {
-        assert_eq!(eval("9C005AC2F8F0"), 0);
     }
 
     #[test]
-    fn test15() {
-        assert_eq!(eval("9C0141080250320F1802104A08"), 1);
+    fn invalid() {
+        assert_eq!(
+            parse("D2FZ28").unwrap_err(),
+            ParseError::new(1, 4, "invalid hex digit 'Z'")
+        );
+        assert!(parse("38006F4").is_err());
     }
 }
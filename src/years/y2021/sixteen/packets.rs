@@ -76,7 +76,7 @@ macro_rules! impl_try_from_bin {
 }
 
 #[derive(Copy, Clone, Debug)]
-pub(crate) struct Literal {
+pub struct Literal {
     version: u8,
     literal: u64,
 }
@@ -107,7 +107,7 @@ impl TryFrom<super::RawPacket> for Literal {
 }
 
 #[derive(Clone, Debug)]
-pub(crate) struct Sum {
+pub struct Sum {
     version: u8,
     operands: Vec<super::Packet>,
 }
@@ -125,7 +125,7 @@ impl Operation for Sum {
 impl_try_from_vec!(Sum);
 
 #[derive(Clone, Debug)]
-pub(crate) struct Product {
+pub struct Product {
     version: u8,
     operands: Vec<super::Packet>,
 }
@@ -143,7 +143,7 @@ impl Operation for Product {
 impl_try_from_vec!(Product);
 
 #[derive(Clone, Debug)]
-pub(crate) struct Minimum {
+pub struct Minimum {
     version: u8,
     operands: Vec<super::Packet>,
 }
@@ -166,7 +166,7 @@ impl Operation for Minimum {
 impl_try_from_vec!(Minimum);
 
 #[derive(Clone, Debug)]
-pub(crate) struct Maximum {
+pub struct Maximum {
     version: u8,
     operands: Vec<super::Packet>,
 }
@@ -189,7 +189,7 @@ impl Operation for Maximum {
 impl_try_from_vec!(Maximum);
 
 #[derive(Clone, Debug)]
-pub(crate) struct GreaterThan {
+pub struct GreaterThan {
     version: u8,
     left: Box<super::Packet>,
     right: Box<super::Packet>,
@@ -212,7 +212,7 @@ impl Operation for GreaterThan {
 impl_try_from_bin!(GreaterThan);
 
 #[derive(Clone, Debug)]
-pub(crate) struct LessThan {
+pub struct LessThan {
     version: u8,
     left: Box<super::Packet>,
     right: Box<super::Packet>,
@@ -235,7 +235,7 @@ impl Operation for LessThan {
 impl_try_from_bin!(LessThan);
 
 #[derive(Clone, Debug)]
-pub(crate) struct EqualTo {
+pub struct EqualTo {
     version: u8,
     left: Box<super::Packet>,
     right: Box<super::Packet>,
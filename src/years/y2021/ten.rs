@@ -1,6 +1,7 @@
 use std::str::FromStr;
 
-const INPUT: &str = include_str!("../../inputs/10");
+use crate::error::{parse_chars, ParseResult};
+use crate::solution::{solution, ParseError};
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
 enum BracketDirection {
@@ -55,7 +56,7 @@ impl FromStr for Bracket {
         if text.len() == 1 {
             text.chars()
                 .next()
-                .ok_or_else(|| ())
+                .ok_or(())
                 .and_then(|c: char| <Bracket as TryFrom<char>>::try_from(c))
         } else {
             Err(())
@@ -91,28 +92,16 @@ enum Error {
 
 impl Error {
     pub fn is_corrupted(&self) -> bool {
-        match self {
-            Self::Corrupted(_) => true,
-            _ => false,
-        }
+        matches!(self, Self::Corrupted(_))
     }
 
     pub fn is_incomplete(&self) -> bool {
-        match self {
-            Self::Incomplete(_) => true,
-            _ => false,
-        }
+        matches!(self, Self::Incomplete(_))
     }
 }
 
-fn parse(text: &str) -> Vec<Vec<Bracket>> {
-    text.lines()
-        .map(|line| {
-            line.chars()
-                .map(|c| c.try_into().unwrap())
-                .collect::<Vec<Bracket>>()
-        })
-        .collect::<Vec<_>>()
+fn parse(text: &str) -> ParseResult<Vec<Vec<Bracket>>> {
+    parse_chars(text, |c| c.try_into().ok())
 }
 
 fn check_errors(line: &Vec<Bracket>) -> Option<Error> {
@@ -123,12 +112,9 @@ fn check_errors(line: &Vec<Bracket>) -> Option<Error> {
                 stack.push(b.get_type());
             }
             BracketDirection::Close => {
-                if let Some(ty) = stack.pop() {
-                    if ty != b.get_type() {
-                        return Some(Error::Corrupted(b.get_type()));
-                    }
-                } else {
-                    unreachable!();
+                // A closing bracket with nothing left to close is corrupted too
+                if stack.pop() != Some(b.get_type()) {
+                    return Some(Error::Corrupted(b.get_type()));
                 }
             }
         }
@@ -162,17 +148,17 @@ fn error_score(error: Error) -> usize {
     }
 }
 
-pub(crate) fn solution1(text: &str) -> usize {
-    parse(text)
+pub fn solution1(text: &str) -> ParseResult<usize> {
+    Ok(parse(text)?
         .into_iter()
         .filter_map(|line| check_errors(&line))
         .filter(Error::is_corrupted)
         .map(error_score)
-        .sum()
+        .sum())
 }
 
-pub(crate) fn solution2(text: &str) -> usize {
-    let mut scores = parse(text)
+pub fn solution2(text: &str) -> ParseResult<usize> {
+    let mut scores = parse(text)?
         .into_iter()
         .filter_map(|line| check_errors(&line))
         .filter(Error::is_incomplete)
@@ -180,36 +166,34 @@ pub(crate) fn solution2(text: &str) -> usize {
         .collect::<Vec<_>>();
     scores.sort();
 
-    scores[scores.len() / 2]
+    scores
+        .get(scores.len() / 2)
+        .copied()
+        .ok_or_else(|| ParseError::unsolvable("no line is incomplete"))
 }
 
-pub fn solution() {
-    println!("Solution 1: {}", solution1(INPUT));
-    println!("Solution 2: {}", solution2(INPUT));
-}
+solution!(10, "Syntax Scoring", parse);
 
 #[cfg(test)]
 mod ten_tests {
     use super::{solution1, solution2};
-
-    const INPUT: &str = "[({(<(())[]>[[{[]{<()<>>
-[(()[<>])]({[<{<<[]>>(
-{([(<{}[<>[]}>{[]{[(<()>
-(((({<>}<{<{<>}{[]{[]{}
-[[<[([]))<([[{}[[()]]]
-[{[{({}]{}}([{[{{{}}([]
-{<[[]]>}<{[{[{[]{()[[[]
-[<(<(<(<{}))><([]([]()
-<{([([[(<>()){}]>(<<{{
-<{([{{}}[<[[[<>{}]]]>[]]";
+    use crate::error::ParseError;
 
     #[test]
-    fn test1() {
-        assert_eq!(solution1(INPUT), 26397);
+    fn invalid() {
+        assert_eq!(
+            solution1("[()]\n(a)"),
+            Err(ParseError::new(2, 2, "invalid character 'a'"))
+        );
+        assert_eq!(solution1(")\n(]>"), Ok(3 + 57));
     }
 
     #[test]
-    fn test2() {
-        assert_eq!(solution2(INPUT), 288957);
+    fn complete() {
+        let err = Err(ParseError::unsolvable("no line is incomplete"));
+        assert_eq!(solution2(""), err);
+        assert_eq!(solution2("()"), err);
+        assert_eq!(solution2("[)"), err);
+        assert_eq!(solution2("[)\n(<"), Ok(21));
     }
 }
@@ -1,6 +1,7 @@
 use std::{collections::BTreeSet, mem::take};
 
-const INPUT: &str = include_str!("../../inputs/13");
+use crate::error::{parse_field, ParseResult};
+use crate::solution::{solution, ParseError};
 
 #[derive(Copy, Clone, Debug)]
 enum Direction {
@@ -48,7 +49,7 @@ impl Matrix {
         }
 
         let right_len = self.width - x - 1;
-        let shift = if right_len > x { right_len - x } else { 0 };
+        let shift = right_len.saturating_sub(x);
         let cut_x = x;
         self.width = x.max(right_len);
         self.points = take(&mut self.points)
@@ -74,7 +75,7 @@ impl Matrix {
         }
 
         let bottom_len = self.height - y - 1;
-        let shift = if bottom_len > y { bottom_len - y } else { 0 };
+        let shift = bottom_len.saturating_sub(y);
         let cut_y = y;
         self.height = y.max(bottom_len);
         self.points = take(&mut self.points)
@@ -103,7 +104,7 @@ impl std::fmt::Display for Matrix {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
         let mut prev: Option<(usize, usize)> = None;
 
-        let mut points: Vec<(usize, usize)> = self.points.iter().map(|p| *p).collect();
+        let mut points: Vec<(usize, usize)> = self.points.iter().copied().collect();
         points.sort_by(|a, b| match a.1.cmp(&b.1) {
             std::cmp::Ordering::Equal => a.0.cmp(&b.0),
             otherwise => otherwise,
@@ -124,7 +125,7 @@ impl std::fmt::Display for Matrix {
                     prev.0 + 1
                 } else {
                     for _ in prev.1..p.1 {
-                        write!(f, "\n")?;
+                        writeln!(f)?;
                     }
                     0
                 };
@@ -140,114 +141,71 @@ impl std::fmt::Display for Matrix {
     }
 }
 
-fn parse_point(text: &str) -> (usize, usize) {
-    let mut ps = text.trim().splitn(2, ",");
+fn parse_point(i: usize, text: &str) -> ParseResult<(usize, usize)> {
+    let (x, y) = text
+        .split_once(',')
+        .ok_or_else(|| ParseError::new(i, 1, "expected a point"))?;
 
-    (
-        ps.next().unwrap().parse().unwrap(),
-        ps.next().unwrap().parse().unwrap(),
-    )
+    Ok((parse_field(i, text, x)?, parse_field(i, text, y)?))
 }
 
-fn parse_instruction(text: &str) -> Instruction {
-    if text.trim().starts_with("fold along ") {
-        let mut ps = text.trim()[11..].splitn(2, "=");
-
-        let dir = match ps.next().unwrap() {
-            "x" | "X" => Direction::X,
-            "y" | "Y" => Direction::Y,
-            _ => unreachable!(),
-        };
-        let amount = ps.next().unwrap().parse().unwrap();
+fn parse_instruction(i: usize, text: &str) -> ParseResult<Instruction> {
+    let fold = text
+        .trim()
+        .strip_prefix("fold along ")
+        .ok_or_else(|| ParseError::new(i, 1, "expected a fold instruction"))?;
+    let (dir, amount) = fold
+        .split_once('=')
+        .ok_or_else(|| ParseError::at(i, text, fold, "expected an axis and a position"))?;
+
+    let dir = match dir {
+        "x" | "X" => Direction::X,
+        "y" | "Y" => Direction::Y,
+        _ => {
+            return Err(ParseError::at(
+                i,
+                text,
+                dir,
+                format!("invalid axis '{}'", dir),
+            ))
+        }
+    };
 
-        Instruction::Fold(dir, amount)
-    } else {
-        unreachable!()
-    }
+    Ok(Instruction::Fold(dir, parse_field(i, text, amount)?))
 }
 
-fn parse(text: &str) -> (Matrix, Vec<Instruction>) {
-    let (_, m, c) = text.trim().lines().fold(
-        (true, Matrix::new(), Vec::new()),
-        |(mut f, mut m, mut c), line| {
-            if line.trim().is_empty() {
-                f = !f;
-            } else {
-                if f {
-                    m.add(parse_point(line));
-                } else {
-                    c.push(parse_instruction(line));
-                }
-            }
-
-            (f, m, c)
-        },
-    );
+fn parse(text: &str) -> ParseResult<(Matrix, Vec<Instruction>)> {
+    let mut matrix = Matrix::new();
+    let mut instructions = Vec::new();
+    let mut points = true;
+
+    // Points come first, then the instructions after an empty line
+    for (i, line) in text.lines().enumerate() {
+        if line.trim().is_empty() {
+            points = false;
+        } else if points {
+            matrix.add(parse_point(i + 1, line)?);
+        } else {
+            instructions.push(parse_instruction(i + 1, line)?);
+        }
+    }
 
-    (m, c)
+    Ok((matrix, instructions))
 }
 
-pub(crate) fn solution1(text: &str) -> usize {
-    let (mut matrix, instructions) = parse(text);
+pub fn solution1(text: &str) -> ParseResult<usize> {
+    let (mut matrix, instructions) = parse(text)?;
     instructions
         .into_iter()
         .take(1)
         .for_each(|i| matrix.execute(i));
-    matrix.len()
+    Ok(matrix.len())
 }
 
-pub(crate) fn solution2(text: &str) -> String {
-    let (mut matrix, instructions) = parse(text);
+pub fn solution2(text: &str) -> ParseResult<String> {
+    let (mut matrix, instructions) = parse(text)?;
     instructions.into_iter().for_each(|i| matrix.execute(i));
-    matrix.to_string()
-}
-
-pub fn solution() {
-    println!("Solution 1: {}", solution1(INPUT));
-    println!("Solution 2:\n{}", solution2(INPUT));
+    Ok(matrix.to_string())
 }
 
-#[cfg(test)]
-mod thirteen_tests {
-    use super::{solution1, solution2};
-
-    const INPUT: &str = "6,10
-0,14
-9,10
-0,3
-10,4
-4,11
-6,0
-6,12
-4,1
-0,13
-10,12
-3,4
-3,0
-8,4
-1,10
-2,14
-8,10
-9,0
-
-fold along y=7
-fold along x=5";
-
-    #[test]
-    fn test1() {
-        assert_eq!(solution1(INPUT), 17);
-    }
-
-    #[test]
-    fn test2() {
-        assert_eq!(
-            solution2(INPUT),
-            "#####
-#   #
-#   #
-#   #
-#####"
-                .to_owned()
-        );
-    }
-}
+solution!(13, "Transparent Origami", parse);
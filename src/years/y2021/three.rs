@@ -0,0 +1,205 @@
+use crate::error::{parse_lines, ParseResult};
+use crate::solution::{solution, ParseError};
+
+fn split(text: &str) -> ParseResult<Vec<Vec<u8>>> {
+    let mut cols = None;
+
+    parse_lines(text, |i, line| {
+        let bits = line
+            .chars()
+            .enumerate()
+            .map(|(j, c)| match c {
+                '0' => Ok(0),
+                '1' => Ok(1),
+                _ => Err(ParseError::new(
+                    i,
+                    j + 1,
+                    format!("invalid character '{}'", c),
+                )),
+            })
+            .collect::<ParseResult<Vec<u8>>>()?;
+
+        if bits.len() > usize::BITS as usize {
+            return Err(ParseError::new(
+                i,
+                usize::BITS as usize + 1,
+                format!("expected at most {} bits", usize::BITS),
+            ));
+        }
+
+        match cols {
+            None => cols = Some(bits.len()),
+            Some(cols) if cols != bits.len() => {
+                return Err(ParseError::new(
+                    i,
+                    cols.min(bits.len()) + 1,
+                    format!("expected {} bits, found {}", cols, bits.len()),
+                ))
+            }
+            _ => (),
+        }
+
+        Ok(bits)
+    })
+}
+
+fn transpose(matrix: Vec<Vec<u8>>) -> Vec<Vec<u8>> {
+    let mut cols = None;
+    let mut res = Vec::new();
+
+    for v in matrix.into_iter() {
+        match cols {
+            None => cols = Some(v.len()),
+            Some(cols) if cols != v.len() => panic!("Invalid number of cols"),
+            _ => (),
+        }
+
+        for (i, val) in v.into_iter().enumerate() {
+            if res.len() <= i {
+                res.push(Vec::new());
+            }
+
+            res[i].push(val);
+        }
+    }
+
+    res
+}
+
+#[derive(Copy, Clone, Debug)]
+struct Count {
+    population: usize,
+    size: usize,
+}
+
+fn popcnt(v: &[u8]) -> usize {
+    v.iter().fold(0, |count, &val| count + (val as usize))
+}
+
+fn count(v: &[u8]) -> Count {
+    Count {
+        population: popcnt(v),
+        size: v.len(),
+    }
+}
+
+fn gamma_rate(count: &Count) -> u8 {
+    if count.population > count.size / 2 {
+        1
+    } else {
+        0
+    }
+}
+
+fn epsilon_rate(count: &Count) -> u8 {
+    if count.population > count.size / 2 {
+        0
+    } else {
+        1
+    }
+}
+
+fn rates(count: Count) -> (u8, u8) {
+    (gamma_rate(&count), epsilon_rate(&count))
+}
+
+/// Reads the bits most significant first, an empty number being zero.
+fn to_number(bits: impl IntoIterator<Item = u8>) -> usize {
+    bits.into_iter()
+        .fold(0, |acc, bit| (acc << 1) | bit as usize)
+}
+
+pub fn solution1(text: &str) -> ParseResult<usize> {
+    let (gamma, epsilon): (Vec<_>, Vec<_>) = transpose(split(text)?)
+        .iter()
+        .map(|v| count(v))
+        .map(rates)
+        .unzip();
+
+    Ok(to_number(gamma) * to_number(epsilon))
+}
+
+/// Keeps the numbers matching `bit_criteria` bit after bit until one is left.
+/// The numbers that all share a bit cannot be told apart by it, so they are
+/// all kept, and the first remaining one is returned.
+fn filter(mut matrix: Vec<Vec<u8>>, bit_criteria: fn(&Count) -> u8) -> Vec<u8> {
+    for i in 0..matrix.first().map_or(0, Vec::len) {
+        if matrix.len() == 1 {
+            break;
+        }
+
+        let count = Count {
+            population: matrix.iter().map(|line| line[i] as usize).sum(),
+            size: matrix.len(),
+        };
+
+        let bit = bit_criteria(&count);
+
+        if matrix.iter().any(|line| line[i] == bit) {
+            matrix.retain(|line| line[i] == bit);
+        }
+    }
+
+    matrix.into_iter().next().unwrap_or_default()
+}
+
+fn oxygen_bit_criteria(count: &Count) -> u8 {
+    if count.population >= count.size - count.population {
+        1
+    } else {
+        0
+    }
+}
+
+fn co2_bit_criteria(count: &Count) -> u8 {
+    if count.population >= count.size - count.population {
+        0
+    } else {
+        1
+    }
+}
+
+fn filter_and_cast(matrix: Vec<Vec<u8>>, bit_criteria: fn(&Count) -> u8) -> usize {
+    to_number(filter(matrix, bit_criteria))
+}
+
+pub fn solution2(text: &str) -> ParseResult<usize> {
+    let matrix = split(text)?;
+    let oxygen_generator_rating = filter_and_cast(matrix.clone(), oxygen_bit_criteria);
+    let co2_scrubber_rating = filter_and_cast(matrix, co2_bit_criteria);
+
+    Ok(oxygen_generator_rating * co2_scrubber_rating)
+}
+
+solution!(3, "Binary Diagnostic", split);
+
+#[cfg(test)]
+mod three_tests {
+    use crate::error::ParseError;
+    use crate::years::y2021::three::{solution1, solution2};
+
+    #[test]
+    pub fn invalid() {
+        assert_eq!(
+            solution1("00100\n11210"),
+            Err(ParseError::new(2, 3, "invalid character '2'"))
+        );
+        assert_eq!(
+            solution1("00100\n1111"),
+            Err(ParseError::new(2, 5, "expected 5 bits, found 4"))
+        );
+        assert_eq!(
+            solution1(&"1".repeat(65)),
+            Err(ParseError::new(1, 65, "expected at most 64 bits"))
+        );
+        assert_eq!(solution1(""), Ok(0));
+        assert_eq!(solution2(""), Ok(0));
+    }
+
+    #[test]
+    pub fn shared_bits() {
+        assert_eq!(solution2("1"), Ok(1));
+        assert_eq!(solution2("1\n1"), Ok(1));
+        assert_eq!(solution2("10\n10\n11"), Ok(2 * 3));
+    }
+}
@@ -4,29 +4,29 @@ use std::{
     sync::Arc,
 };
 
+use crate::error::{parse_lines, ParseResult};
 use crate::graph::Graph;
+use crate::solution::{solution, ParseError};
 
-const INPUT: &str = include_str!("../../inputs/12");
-
-fn raw_parse(text: &str) -> Vec<(String, String)> {
-    text.lines()
-        .map(|line| {
-            let mut ps = line.splitn(2, '-').map(ToString::to_string);
-            (ps.next().unwrap(), ps.next().unwrap())
-        })
-        .collect()
+fn raw_parse(text: &str) -> ParseResult<Vec<(String, String)>> {
+    parse_lines(text, |i, line| match line.split_once('-') {
+        Some((a, b)) => Ok((a.to_string(), b.to_string())),
+        None => Err(ParseError::new(i, 1, "expected a '-' separated edge")),
+    })
 }
 
-fn parse(text: &str) -> Graph<String> {
-    Graph::from_edges(raw_parse(text))
+fn parse(text: &str) -> ParseResult<Graph<String>> {
+    Ok(Graph::from_edges(raw_parse(text)?))
 }
 
+type ValidOccurrences<'a, T> = dyn Fn(&BTreeMap<&'a T, usize>, &'a T) -> bool + 'a;
+
 #[derive(Clone)]
 struct State<'a, T> {
-    path: Cow<'a, Vec<&'a T>>,
+    path: Cow<'a, [&'a T]>,
     non_repeatables: Cow<'a, BTreeMap<&'a T, usize>>,
     is_small: Arc<Box<dyn Fn(&'a T) -> bool + 'a>>,
-    valid_occurrences: Arc<Box<dyn Fn(&BTreeMap<&'a T, usize>, &'a T) -> bool + 'a>>,
+    valid_occurrences: Arc<Box<ValidOccurrences<'a, T>>>,
 }
 
 impl<'a, T: std::fmt::Debug> std::fmt::Debug for State<'a, T> {
@@ -55,7 +55,7 @@ impl<'a, T: Ord> State<'a, T> {
 
     pub fn get_occurrences(&self, element: &'a T) -> Option<usize> {
         if self.is_small(element) {
-            return Some(self.non_repeatables.get(element).map(|x| *x).unwrap_or(0));
+            return Some(self.non_repeatables.get(element).copied().unwrap_or(0));
         }
 
         None
@@ -99,9 +99,10 @@ where
     let mut paths = Vec::new();
     let mut stack: VecDeque<State<'a, T>> = VecDeque::new();
 
-    {
+    // Without a start, there is no path at all
+    if let Some(start) = graph.nodes().find(|&n| n == start) {
         let mut state = State::new(is_small, valid_occurrences);
-        state.push(graph.nodes().find(|&n| n == start).unwrap());
+        state.push(start);
         stack.push_back(state);
     }
 
@@ -123,109 +124,43 @@ where
     paths
 }
 
-fn is_small_cave(cave: &String) -> bool {
+fn is_small_cave(cave: &str) -> bool {
     cave.chars().find(|c| c.is_uppercase()).is_none()
 }
 
-pub(crate) fn solution1(text: &str) -> usize {
-    let graph = parse(text);
+pub fn solution1(text: &str) -> ParseResult<usize> {
+    let graph = parse(text)?;
     let start = "start".to_owned();
     let end = "end".to_owned();
-    let paths = all_paths(&graph, &start, &end, is_small_cave, |occurrences, key| {
-        occurrences.get(key).map(|x| *x).unwrap_or(0) < 1
-    });
-    paths.len()
+    let paths = all_paths(
+        &graph,
+        &start,
+        &end,
+        |cave| is_small_cave(cave),
+        |occurrences, key| occurrences.get(key).copied().unwrap_or(0) < 1,
+    );
+    Ok(paths.len())
 }
 
-pub(crate) fn solution2(text: &str) -> usize {
-    let graph = parse(text);
+pub fn solution2(text: &str) -> ParseResult<usize> {
+    let graph = parse(text)?;
     let start = "start".to_owned();
     let end = "end".to_owned();
-    let paths = all_paths(&graph, &start, &end, is_small_cave, |occurrences, key| {
-        let max = if occurrences.values().map(|x| *x).max().unwrap_or(0) == 2 {
-            1
-        } else {
-            2
-        };
-        occurrences.get(key).map(|x| *x).unwrap_or(0) < max
-    });
-    paths.len()
-}
-
-pub fn solution() {
-    println!("Solution 1: {}", solution1(INPUT));
-    println!("Solution 2: {}", solution2(INPUT));
+    let paths = all_paths(
+        &graph,
+        &start,
+        &end,
+        |cave| is_small_cave(cave),
+        |occurrences, key| {
+            let max = if occurrences.values().copied().max().unwrap_or(0) == 2 {
+                1
+            } else {
+                2
+            };
+            occurrences.get(key).copied().unwrap_or(0) < max
+        },
+    );
+    Ok(paths.len())
 }
 
-#[cfg(test)]
-mod twelve_tests {
-    use super::{solution1, solution2};
-
-    const INPUT1: &str = "start-A
-start-b
-A-c
-A-b
-b-d
-A-end
-b-end";
-
-    const INPUT2: &str = "dc-end
-HN-start
-start-kj
-dc-start
-dc-HN
-LN-dc
-HN-end
-kj-sa
-kj-HN
-kj-dc";
-
-    const INPUT3: &str = "fs-end
-he-DX
-fs-he
-start-DX
-pj-DX
-end-zg
-zg-sl
-zg-pj
-pj-he
-RW-he
-fs-DX
-pj-RW
-zg-RW
-start-pj
-he-WI
-zg-he
-pj-fs
-start-RW";
-
-    #[test]
-    fn test1() {
-        assert_eq!(solution1(INPUT1), 10);
-    }
-
-    #[test]
-    fn test2() {
-        assert_eq!(solution1(INPUT2), 19);
-    }
-
-    #[test]
-    fn test3() {
-        assert_eq!(solution1(INPUT3), 226);
-    }
-
-    #[test]
-    fn test4() {
-        assert_eq!(solution2(INPUT1), 36);
-    }
-
-    #[test]
-    fn test5() {
-        assert_eq!(solution2(INPUT2), 103);
-    }
-
-    #[test]
-    fn test6() {
-        assert_eq!(solution2(INPUT3), 3509);
-    }
-}
+solution!(12, "Passage Pathing", parse);
@@ -0,0 +1,257 @@
+use std::{cmp::Ordering, collections::BTreeSet, mem::swap};
+
+use crate::error::ParseResult;
+use crate::solution::{solution, ParseError};
+
+fn matrix_iter(width: usize, height: usize) -> impl Iterator<Item = Coord> {
+    (0..height).flat_map(move |y| (0..(width)).map(move |x| (x, y).into()))
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+struct Coord {
+    x: usize,
+    y: usize,
+}
+
+impl PartialOrd for Coord {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Coord {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match Ord::cmp(&self.y, &other.y) {
+            Ordering::Equal => Ord::cmp(&self.x, &other.x),
+            o => o,
+        }
+    }
+}
+
+impl From<(usize, usize)> for Coord {
+    fn from((x, y): (usize, usize)) -> Self {
+        Coord { x, y }
+    }
+}
+
+impl From<Coord> for (usize, usize) {
+    fn from(val: Coord) -> Self {
+        (val.x, val.y)
+    }
+}
+
+#[derive(Clone, Debug)]
+struct Image {
+    pixels: BTreeSet<Coord>,
+    width: usize,
+    height: usize,
+}
+
+impl Image {
+    #[inline]
+    pub fn new(width: usize, height: usize, pixels: BTreeSet<Coord>) -> Self {
+        Self {
+            width,
+            height,
+            pixels,
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, coord: &Coord) -> Pixel {
+        if self.pixels.contains(coord) {
+            Pixel::Light
+        } else {
+            Pixel::Dark
+        }
+    }
+
+    pub fn iter<'a>(&'a self) -> impl Iterator<Item = Pixel> + 'a {
+        matrix_iter(self.width(), self.height()).map(|c| self.get(&c))
+    }
+
+    pub fn enhance(&self, algo: &Algorithm, times: usize) -> Image {
+        algo.enhance(self, times)
+    }
+
+    pub fn lit_len(&self) -> usize {
+        self.pixels.len()
+    }
+}
+
+impl std::fmt::Display for Image {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        let mut count = 0usize;
+        for pixel in self.iter() {
+            count += 1;
+            if count > self.width() {
+                count = 1;
+                writeln!(f)?;
+            }
+            write!(
+                f,
+                "{}",
+                match pixel {
+                    Pixel::Light => '#',
+                    Pixel::Dark => ' ',
+                }
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Default)]
+enum Pixel {
+    Light,
+    #[default]
+    Dark,
+}
+
+impl From<Pixel> for usize {
+    fn from(val: Pixel) -> Self {
+        match val {
+            Pixel::Light => 1,
+            Pixel::Dark => 0,
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Algorithm(BTreeSet<usize>);
+
+impl Algorithm {
+    #[inline]
+    pub fn new(pixels: BTreeSet<usize>) -> Algorithm {
+        Self(pixels)
+    }
+
+    pub fn _enhance(&self, image: &Image, def: usize) -> Image {
+        let new_width = image.width() + 2;
+        let new_height = image.height() + 2;
+        // The image is grown by a pixel on every side, and the 3×3 square
+        // around a pixel is walked one past it to stay unsigned
+        let x_range = 2..=image.width() + 1;
+        let y_range = 2..=image.height() + 1;
+
+        let pixels = matrix_iter(new_width, new_height)
+            .filter(|c| {
+                let mut idx = 0;
+                for y in c.y..c.y + 3 {
+                    for x in c.x..c.x + 3 {
+                        let bit = if x_range.contains(&x) && y_range.contains(&y) {
+                            image.get(&(x - 2, y - 2).into()).into()
+                        } else {
+                            def
+                        };
+                        idx = (idx << 1) | bit;
+                    }
+                }
+
+                self.0.contains(&idx)
+            })
+            .collect::<BTreeSet<Coord>>();
+
+        Image::new(new_width, new_height, pixels)
+    }
+
+    pub fn enhance(&self, image: &Image, mut times: usize) -> Image {
+        let mut def0: usize = 0;
+        let mut def1: usize = if self.0.contains(&0) { 1 } else { 0 };
+
+        let mut image = if times < 1 {
+            return image.clone();
+        } else {
+            times -= 1;
+            swap(&mut def0, &mut def1);
+            self._enhance(image, def1)
+        };
+
+        while times > 0 {
+            image = self._enhance(&image, def0);
+            swap(&mut def0, &mut def1);
+            times -= 1;
+        }
+
+        image
+    }
+}
+
+/// Whether the pixel at the given 1-based line and 0-based column is lit.
+fn parse_pixel(line: usize, x: usize, c: char) -> ParseResult<bool> {
+    match c {
+        '#' => Ok(true),
+        '.' => Ok(false),
+        _ => Err(ParseError::new(
+            line,
+            x + 1,
+            format!("invalid pixel '{}'", c),
+        )),
+    }
+}
+
+fn parse_algo(text: &str) -> ParseResult<Algorithm> {
+    let mut pixels = BTreeSet::new();
+
+    for (i, c) in text.trim_end().chars().enumerate() {
+        if parse_pixel(1, i, c)? {
+            pixels.insert(i);
+        }
+    }
+
+    Ok(Algorithm::new(pixels))
+}
+
+/// Parses the image whose first line is the `first`-th line of the input.
+fn parse_image(first: usize, lines: &[&str]) -> ParseResult<Image> {
+    let mut pixels = BTreeSet::new();
+    let mut width = 0;
+    let mut height = 0;
+
+    for (y, l) in lines.iter().enumerate() {
+        height = height.max(y);
+        for (x, c) in l.trim_end().chars().enumerate() {
+            width = width.max(x);
+            if parse_pixel(first + y, x, c)? {
+                pixels.insert((x, y).into());
+            }
+        }
+    }
+
+    Ok(Image::new(width + 1, height + 1, pixels))
+}
+
+fn parse(text: &str) -> ParseResult<(Algorithm, Image)> {
+    let lines = text.trim_end().lines().collect::<Vec<_>>();
+    let algo = parse_algo(lines.first().copied().unwrap_or_default())?;
+
+    match lines.get(1) {
+        Some(line) if line.trim().is_empty() => (),
+        _ => return Err(ParseError::new(2, 1, "expected an empty line")),
+    }
+
+    Ok((algo, parse_image(3, &lines[2..])?))
+}
+
+fn solve(text: &str, times: usize) -> ParseResult<usize> {
+    let (algo, image) = parse(text)?;
+    Ok(image.enhance(&algo, times).lit_len())
+}
+
+pub fn solution1(text: &str) -> ParseResult<usize> {
+    solve(text, 2)
+}
+
+pub fn solution2(text: &str) -> ParseResult<usize> {
+    solve(text, 50)
+}
+
+solution!(20, "Trench Map", parse);
@@ -0,0 +1,490 @@
+use std::fmt;
+
+use crate::error::{trim_blank_lines, ParseResult};
+use crate::solution::{solution, ParseError};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Direction {
+    EAST,
+    SOUTH,
+}
+
+impl Direction {
+    pub fn iter() -> impl Iterator<Item = Direction> {
+        [Direction::EAST, Direction::SOUTH].into_iter()
+    }
+}
+
+const BITS: usize = u64::BITS as usize;
+
+/// Bit `x` of `out` is bit `x + 1` of `row`, wrapping around at `width`.
+fn rotate_next(row: &[u64], width: usize, out: &mut [u64]) {
+    for (i, word) in out.iter_mut().enumerate() {
+        let carry = row.get(i + 1).map_or(0, |next| next << (BITS - 1));
+        *word = row[i] >> 1 | carry;
+    }
+    let last = width - 1;
+    out[last / BITS] |= (row[0] & 1) << (last % BITS);
+}
+
+/// Bit `x` of `out` is bit `x - 1` of `row`, wrapping around at `width`.
+fn rotate_prev(row: &[u64], width: usize, out: &mut [u64]) {
+    for (i, word) in out.iter_mut().enumerate() {
+        let carry = if i == 0 { 0 } else { row[i - 1] >> (BITS - 1) };
+        *word = row[i] << 1 | carry;
+    }
+    if !width.is_multiple_of(BITS) {
+        out[width / BITS] &= !(1 << (width % BITS));
+    }
+    let last = width - 1;
+    out[0] |= (row[last / BITS] >> (last % BITS)) & 1;
+}
+
+/// The sea floor, every herd being a bitset per row: bit `x % 64` of word
+/// `y * words + x / 64` is set when one of its sea cucumbers is at `(x, y)`.
+/// Bits past the width are always clear, so that whole rows move at once.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Matrix {
+    width: usize,
+    height: usize,
+    words: usize,
+    east: Vec<u64>,
+    south: Vec<u64>,
+}
+
+impl Matrix {
+    pub fn parse(text: &str) -> ParseResult<Self> {
+        let text = trim_blank_lines(text);
+        // Shorter rows are padded with empty cells
+        let width = text.lines().map(|line| line.chars().count()).max();
+        let width = width.unwrap_or(0);
+        let height = text.lines().count();
+        let words = width.div_ceil(BITS);
+        let mut matrix = Self {
+            width,
+            height,
+            words,
+            east: vec![0; words * height],
+            south: vec![0; words * height],
+        };
+
+        for (y, line) in text.lines().enumerate() {
+            for (x, c) in line.chars().enumerate() {
+                let herd = match c {
+                    '.' => continue,
+                    'v' => &mut matrix.south,
+                    '>' => &mut matrix.east,
+                    _ => {
+                        return Err(ParseError::new(
+                            y + 1,
+                            x + 1,
+                            format!("invalid character '{}'", c),
+                        ))
+                    }
+                };
+                herd[y * words + x / BITS] |= 1 << (x % BITS);
+            }
+        }
+
+        Ok(matrix)
+    }
+
+    pub fn size(&self) -> (usize, usize) {
+        (self.width, self.height)
+    }
+
+    fn herd(&self, dir: Direction) -> &[u64] {
+        match dir {
+            Direction::EAST => &self.east,
+            Direction::SOUTH => &self.south,
+        }
+    }
+
+    fn contains(&self, dir: Direction, (x, y): (usize, usize)) -> bool {
+        self.herd(dir)[y * self.words + x / BITS] >> (x % BITS) & 1 != 0
+    }
+
+    pub fn get(&self, pos: (usize, usize)) -> Option<Direction> {
+        Direction::iter().find(|&dir| self.contains(dir, pos))
+    }
+
+    /// Positions of the sea cucumbers of a herd, row by row.
+    pub fn positions(&self, dir: Direction) -> impl Iterator<Item = (usize, usize)> + '_ {
+        (0..self.height)
+            .flat_map(move |y| (0..self.width).map(move |x| (x, y)))
+            .filter(move |&pos| self.contains(dir, pos))
+    }
+
+    pub fn step(&mut self) -> usize {
+        let mut count = 0;
+
+        for dir in Direction::iter() {
+            count += self.step_direction(dir);
+        }
+
+        count
+    }
+
+    /// Moves a whole herd at once: every sea cucumber looks at the grid as it
+    /// was before any of them moved.
+    fn step_direction(&mut self, dir: Direction) -> usize {
+        if self.width == 0 || self.height == 0 {
+            return 0;
+        }
+        match dir {
+            Direction::EAST => self.step_east(),
+            Direction::SOUTH => self.step_south(),
+        }
+    }
+
+    fn step_east(&mut self) -> usize {
+        let mut count = 0;
+        let mut occupied = vec![0; self.words];
+        let mut blocked = vec![0; self.words];
+        let mut moving = vec![0; self.words];
+        let mut arrived = vec![0; self.words];
+
+        for y in 0..self.height {
+            let row = y * self.words..(y + 1) * self.words;
+            let (east, south) = (&mut self.east[row.clone()], &self.south[row]);
+
+            for i in 0..self.words {
+                occupied[i] = east[i] | south[i];
+            }
+            rotate_next(&occupied, self.width, &mut blocked);
+            for i in 0..self.words {
+                moving[i] = east[i] & !blocked[i];
+                count += moving[i].count_ones() as usize;
+            }
+            rotate_prev(&moving, self.width, &mut arrived);
+            for i in 0..self.words {
+                east[i] = east[i] & !moving[i] | arrived[i];
+            }
+        }
+
+        count
+    }
+
+    fn step_south(&mut self) -> usize {
+        let words = self.words;
+        let row = |y: usize| y * words..(y + 1) * words;
+
+        // Every row looks at the next one before anything moves, as the last
+        // row wraps around to the first
+        let mut moving = vec![0; self.south.len()];
+        for y in 0..self.height {
+            let next = (y + 1) % self.height;
+            for (i, j) in row(y).zip(row(next)) {
+                moving[i] = self.south[i] & !(self.east[j] | self.south[j]);
+            }
+        }
+
+        for y in 0..self.height {
+            let next = (y + 1) % self.height;
+            for (i, j) in row(y).zip(row(next)) {
+                self.south[i] &= !moving[i];
+                self.south[j] |= moving[i];
+            }
+        }
+
+        moving.iter().map(|word| word.count_ones() as usize).sum()
+    }
+
+    /// Iterates over the state after every step, up to the first step where
+    /// no sea cucumber moves. Herds that never settle make it endless.
+    pub fn steps(&self) -> Steps {
+        Steps {
+            matrix: self.clone(),
+            done: false,
+        }
+    }
+
+    /// Finds where the herds start repeating themselves, with Brent's
+    /// algorithm so that only a couple of states are kept around.
+    pub fn cycle(&self) -> Cycle {
+        let next = |matrix: &Matrix| {
+            let mut matrix = matrix.clone();
+            matrix.step();
+            matrix
+        };
+
+        let mut power = 1;
+        let mut length = 1;
+        let mut tortoise = self.clone();
+        let mut hare = next(self);
+        while tortoise != hare {
+            if power == length {
+                tortoise = hare.clone();
+                power *= 2;
+                length = 0;
+            }
+            hare.step();
+            length += 1;
+        }
+
+        let mut start = 0;
+        let mut tortoise = self.clone();
+        let mut hare = self.clone();
+        for _ in 0..length {
+            hare.step();
+        }
+        while tortoise != hare {
+            tortoise.step();
+            hare.step();
+            start += 1;
+        }
+
+        Cycle { start, length }
+    }
+}
+
+impl fmt::Display for Matrix {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for y in 0..self.height {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for x in 0..self.width {
+                let c = match self.get((x, y)) {
+                    Some(Direction::EAST) => '>',
+                    Some(Direction::SOUTH) => 'v',
+                    None => '.',
+                };
+                write!(f, "{}", c)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+pub struct Steps {
+    matrix: Matrix,
+    done: bool,
+}
+
+impl Iterator for Steps {
+    type Item = Matrix;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        self.done = self.matrix.step() == 0;
+        Some(self.matrix.clone())
+    }
+}
+
+/// The herds are in the same state after `start` steps and after
+/// `start + length` steps.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Cycle {
+    pub start: usize,
+    pub length: usize,
+}
+
+impl Cycle {
+    /// Whether the herds stop moving altogether after `start` steps.
+    pub fn is_fixed_point(&self) -> bool {
+        self.length == 1
+    }
+}
+
+impl fmt::Display for Cycle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_fixed_point() {
+            write!(f, "fixed point after {} steps", self.start)
+        } else {
+            write!(
+                f,
+                "cycle of {} steps after {} steps",
+                self.length, self.start
+            )
+        }
+    }
+}
+
+pub fn solution1(text: &str) -> ParseResult<usize> {
+    let mut matrix = Matrix::parse(text)?;
+    let mut count = 1;
+    while matrix.step() != 0 {
+        count += 1;
+    }
+    Ok(count)
+}
+
+/// There is no second puzzle on the last day, so this describes how the herds
+/// end up instead. Where they end up is rendered by the last of
+/// [`Matrix::steps`].
+pub fn solution2(text: &str) -> ParseResult<String> {
+    Ok(Matrix::parse(text)?.cycle().to_string())
+}
+
+solution!(25, "Sea Cucumber", Matrix::parse);
+
+#[cfg(test)]
+mod twentyfive_tests {
+    use std::collections::BTreeSet;
+
+    use super::{Cycle, Direction, Matrix};
+
+    #[test]
+    fn test1() {
+        let mut matrix = Matrix::parse("...>>>>>...").unwrap();
+        assert_eq!(
+            &matrix.positions(Direction::EAST).collect::<BTreeSet<_>>(),
+            &[(3, 0), (4, 0), (5, 0), (6, 0), (7, 0)]
+                .into_iter()
+                .collect()
+        );
+        assert_eq!(matrix.step(), 1);
+        assert_eq!(
+            &matrix.positions(Direction::EAST).collect::<BTreeSet<_>>(),
+            &[(3, 0), (4, 0), (5, 0), (6, 0), (8, 0)]
+                .into_iter()
+                .collect()
+        );
+
+        assert_eq!(matrix.step(), 2);
+        assert_eq!(
+            &matrix.positions(Direction::EAST).collect::<BTreeSet<_>>(),
+            &[(3, 0), (4, 0), (5, 0), (7, 0), (9, 0)]
+                .into_iter()
+                .collect()
+        );
+
+        assert_eq!(matrix.step(), 3);
+        assert_eq!(
+            &matrix.positions(Direction::EAST).collect::<BTreeSet<_>>(),
+            &[(3, 0), (4, 0), (6, 0), (8, 0), (10, 0)]
+                .into_iter()
+                .collect()
+        );
+    }
+
+    #[test]
+    fn test2() {
+        let mut matrix = Matrix::parse(
+            "..........
+.>v....v..
+.......>..
+..........",
+        )
+        .unwrap();
+
+        assert_eq!(
+            &matrix.positions(Direction::EAST).collect::<BTreeSet<_>>(),
+            &[(1, 1), (7, 2)].into_iter().collect()
+        );
+        assert_eq!(
+            &matrix.positions(Direction::SOUTH).collect::<BTreeSet<_>>(),
+            &[(2, 1), (7, 1)].into_iter().collect()
+        );
+
+        matrix.step();
+        assert_eq!(
+            &matrix.positions(Direction::EAST).collect::<BTreeSet<_>>(),
+            &[(1, 1), (8, 2)].into_iter().collect()
+        );
+        assert_eq!(
+            &matrix.positions(Direction::SOUTH).collect::<BTreeSet<_>>(),
+            &[(2, 2), (7, 2)].into_iter().collect()
+        );
+    }
+
+    /// Rows wider than a word, wrapping around across word boundaries.
+    #[test]
+    fn wide() {
+        let mut matrix =
+            Matrix::parse(&format!("{}>>\n{}v.", ".".repeat(63), ".".repeat(63))).unwrap();
+
+        assert_eq!(matrix.step(), 1);
+        assert_eq!(
+            matrix.positions(Direction::EAST).collect::<Vec<_>>(),
+            [(0, 0), (63, 0)]
+        );
+        assert_eq!(
+            matrix.positions(Direction::SOUTH).collect::<Vec<_>>(),
+            [(63, 1)]
+        );
+
+        assert_eq!(matrix.step(), 3);
+        assert_eq!(
+            matrix.positions(Direction::EAST).collect::<Vec<_>>(),
+            [(1, 0), (64, 0)]
+        );
+        assert_eq!(matrix.get((63, 0)), Some(Direction::SOUTH));
+    }
+
+    #[test]
+    fn steps() {
+        let matrix = Matrix::parse(
+            "...>...
+.......
+......>
+v.....>
+......>
+.......
+..vvv..",
+        )
+        .unwrap();
+        assert_eq!(
+            matrix.to_string(),
+            "...>...
+.......
+......>
+v.....>
+......>
+.......
+..vvv.."
+        );
+
+        let steps = matrix.steps().take(4).collect::<Vec<_>>();
+        assert_eq!(
+            steps[0].to_string(),
+            "..vv>..
+.......
+>......
+v.....>
+>......
+.......
+....v.."
+        );
+        assert_eq!(
+            steps[3].to_string(),
+            ">......
+..v....
+..>.v..
+.>.v...
+...>...
+.......
+v......"
+        );
+        assert_eq!(
+            matrix.cycle(),
+            Cycle {
+                start: 4,
+                length: 7
+            }
+        );
+    }
+
+    #[test]
+    fn cycles() {
+        let matrix = Matrix::parse(">.").unwrap();
+        assert_eq!(
+            matrix.cycle(),
+            Cycle {
+                start: 0,
+                length: 2
+            }
+        );
+        assert!(!matrix.cycle().is_fixed_point());
+        assert_eq!(matrix.cycle().to_string(), "cycle of 2 steps after 0 steps");
+
+        let matrix = Matrix::parse(">>\nvv").unwrap();
+        assert!(matrix.cycle().is_fixed_point());
+        assert_eq!(matrix.steps().count(), 1);
+    }
+}
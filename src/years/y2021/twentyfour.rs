@@ -0,0 +1,411 @@
+use std::fmt;
+
+use crate::error::{parse_field, parse_lines, ParseResult};
+use crate::solution::{solution, ParseError};
+
+pub type Value = i64;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Register {
+    W,
+    X,
+    Y,
+    Z,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Operand {
+    Register(Register),
+    Value(Value),
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Instruction {
+    Inp(Register),
+    Add(Register, Operand),
+    Mul(Register, Operand),
+    Div(Register, Operand),
+    Mod(Register, Operand),
+    Eql(Register, Operand),
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum AluError {
+    /// An `inp` instruction was reached after every input had been read
+    MissingInput(usize),
+    DivisionByZero(usize),
+    /// `mod a b` with `a < 0` or `b <= 0`
+    InvalidModulo(usize),
+    /// An `add` or `mul` result does not fit in a [`Value`]
+    Overflow(usize),
+}
+
+impl AluError {
+    /// The 0-based index of the failing instruction.
+    pub fn pc(&self) -> usize {
+        match *self {
+            Self::MissingInput(pc)
+            | Self::DivisionByZero(pc)
+            | Self::InvalidModulo(pc)
+            | Self::Overflow(pc) => pc,
+        }
+    }
+}
+
+impl fmt::Display for AluError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingInput(pc) => write!(f, "instruction {}: missing input", pc + 1),
+            Self::DivisionByZero(pc) => write!(f, "instruction {}: division by zero", pc + 1),
+            Self::InvalidModulo(pc) => write!(f, "instruction {}: invalid modulo", pc + 1),
+            Self::Overflow(pc) => write!(f, "instruction {}: overflow", pc + 1),
+        }
+    }
+}
+
+impl std::error::Error for AluError {}
+
+/// The four registers of the ALU, all starting at zero.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct Alu {
+    registers: [Value; 4],
+}
+
+impl Alu {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn get(&self, register: Register) -> Value {
+        self.registers[register as usize]
+    }
+
+    fn value(&self, operand: Operand) -> Value {
+        match operand {
+            Operand::Register(register) => self.get(register),
+            Operand::Value(value) => value,
+        }
+    }
+
+    /// Runs `program`, reading `inp` values from `input` in order.
+    pub fn run<I: IntoIterator<Item = Value>>(
+        &mut self,
+        program: &[Instruction],
+        input: I,
+    ) -> Result<(), AluError> {
+        let mut input = input.into_iter();
+
+        for (pc, &instruction) in program.iter().enumerate() {
+            let (register, value) = match instruction {
+                Instruction::Inp(a) => (a, input.next().ok_or(AluError::MissingInput(pc))?),
+                Instruction::Add(a, b) => match self.get(a).checked_add(self.value(b)) {
+                    Some(value) => (a, value),
+                    None => return Err(AluError::Overflow(pc)),
+                },
+                Instruction::Mul(a, b) => match self.get(a).checked_mul(self.value(b)) {
+                    Some(value) => (a, value),
+                    None => return Err(AluError::Overflow(pc)),
+                },
+                Instruction::Div(a, b) => match self.value(b) {
+                    0 => return Err(AluError::DivisionByZero(pc)),
+                    b => (a, self.get(a) / b),
+                },
+                Instruction::Mod(a, b) => match (self.get(a), self.value(b)) {
+                    (n, m) if n < 0 || m <= 0 => return Err(AluError::InvalidModulo(pc)),
+                    (n, m) => (a, n % m),
+                },
+                Instruction::Eql(a, b) => (a, (self.get(a) == self.value(b)) as Value),
+            };
+            self.registers[register as usize] = value;
+        }
+
+        Ok(())
+    }
+}
+
+fn parse_register(i: usize, line: &str, text: &str) -> ParseResult<Register> {
+    match text {
+        "w" => Ok(Register::W),
+        "x" => Ok(Register::X),
+        "y" => Ok(Register::Y),
+        "z" => Ok(Register::Z),
+        _ => Err(ParseError::at(
+            i,
+            line,
+            text,
+            format!("invalid register '{}'", text),
+        )),
+    }
+}
+
+fn parse_operand(i: usize, line: &str, text: &str) -> ParseResult<Operand> {
+    if text.starts_with(|c: char| c.is_ascii_alphabetic()) {
+        parse_register(i, line, text).map(Operand::Register)
+    } else {
+        parse_field(i, line, text).map(Operand::Value)
+    }
+}
+
+fn parse_instruction(i: usize, line: &str) -> ParseResult<Instruction> {
+    let words = line.split_whitespace().collect::<Vec<_>>();
+
+    let binary = |op: fn(Register, Operand) -> Instruction| match words[1..] {
+        [a, b] => Ok(op(parse_register(i, line, a)?, parse_operand(i, line, b)?)),
+        _ => Err(ParseError::new(i, 1, "expected two operands")),
+    };
+
+    match words.first() {
+        Some(&"inp") => match words[1..] {
+            [a] => Ok(Instruction::Inp(parse_register(i, line, a)?)),
+            _ => Err(ParseError::new(i, 1, "expected a single register")),
+        },
+        Some(&"add") => binary(Instruction::Add),
+        Some(&"mul") => binary(Instruction::Mul),
+        Some(&"div") => binary(Instruction::Div),
+        Some(&"mod") => binary(Instruction::Mod),
+        Some(&"eql") => binary(Instruction::Eql),
+        Some(op) => Err(ParseError::at(
+            i,
+            line,
+            op,
+            format!("invalid instruction '{}'", op),
+        )),
+        None => Err(ParseError::new(i, 1, "missing instruction")),
+    }
+}
+
+pub fn parse(text: &str) -> ParseResult<Vec<Instruction>> {
+    parse_lines(text, parse_instruction)
+}
+
+/// MONAD checks every digit with the same block of instructions, which only
+/// differs by the three constants marked `{}`.
+const BLOCK: [&str; 18] = [
+    "inp w", "mul x 0", "add x z", "mod x 26", "div z {}", "add x {}", "eql x w", "eql x 0",
+    "mul y 0", "add y 25", "mul y x", "add y 1", "mul z y", "mul y 0", "add y w", "add y {}",
+    "mul y x", "add z y",
+];
+const DIGITS: usize = 14;
+
+/// The constants of a digit's block. `z` is used as a base 26 stack: a block
+/// with `div z 1` pushes `w + offset`, one with `div z 26` pops the top value
+/// `v` and pushes again unless `w == v + check`. A number is valid when `z`
+/// ends up empty, so every pop has to match its push.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+struct Block {
+    pop: bool,
+    check: Value,
+    offset: Value,
+}
+
+fn analyze(program: &[Instruction]) -> ParseResult<Vec<Block>> {
+    if program.len() != BLOCK.len() * DIGITS {
+        return Err(ParseError::new(
+            1,
+            1,
+            format!(
+                "expected {} instructions, found {}",
+                BLOCK.len() * DIGITS,
+                program.len()
+            ),
+        ));
+    }
+
+    let mut blocks = Vec::new();
+    for (digit, chunk) in program.chunks(BLOCK.len()).enumerate() {
+        let mut constants = Vec::new();
+        for (k, (&instruction, template)) in chunk.iter().zip(BLOCK).enumerate() {
+            let i = digit * BLOCK.len() + k + 1;
+            let expected = parse_instruction(i, &template.replace("{}", "0"))?;
+            match (instruction, expected) {
+                (
+                    Instruction::Div(a, Operand::Value(n)),
+                    Instruction::Div(b, Operand::Value(_)),
+                )
+                | (
+                    Instruction::Add(a, Operand::Value(n)),
+                    Instruction::Add(b, Operand::Value(_)),
+                ) if template.contains("{}") && a == b => constants.push(n),
+                (instruction, expected) if instruction == expected => (),
+                _ => return Err(ParseError::new(i, 1, format!("expected '{}'", template))),
+            }
+        }
+
+        let i = digit * BLOCK.len() + 5;
+        match constants[..] {
+            [1, check, offset] => blocks.push(Block {
+                pop: false,
+                check,
+                offset,
+            }),
+            [26, check, offset] => blocks.push(Block {
+                pop: true,
+                check,
+                offset,
+            }),
+            _ => return Err(ParseError::new(i, 1, "expected 'div z 1' or 'div z 26'")),
+        }
+    }
+
+    Ok(blocks)
+}
+
+/// Pairs every pushing digit with the digit popping it: `w[pop] == w[push] +
+/// offset[push] + check[pop]`. Each pair is then set independently, with its
+/// digits as high (or low) as possible.
+fn model_number(blocks: &[Block], largest: bool) -> Option<[Value; DIGITS]> {
+    let mut digits = [0; DIGITS];
+    let mut stack = Vec::new();
+
+    for (i, block) in blocks.iter().enumerate() {
+        if !block.pop {
+            stack.push((i, block.offset));
+            continue;
+        }
+
+        let (j, offset) = stack.pop()?;
+        let diff = offset + block.check;
+        let (wj, wi) = match (largest, diff > 0) {
+            (true, true) => (9 - diff, 9),
+            (true, false) => (9, 9 + diff),
+            (false, true) => (1, 1 + diff),
+            (false, false) => (1 - diff, 1),
+        };
+        if !(1..=9).contains(&wi) || !(1..=9).contains(&wj) {
+            return None;
+        }
+        digits[i] = wi;
+        digits[j] = wj;
+    }
+
+    stack.is_empty().then_some(digits)
+}
+
+fn solve(text: &str, largest: bool) -> ParseResult<u64> {
+    let program = parse(text)?;
+    let blocks = analyze(&program)?;
+    let digits = model_number(&blocks, largest)
+        .ok_or_else(|| ParseError::unsolvable("MONAD accepts no model number"))?;
+    let number = digits.iter().fold(0, |acc, &d| acc * 10 + d as u64);
+
+    // The analysis only relies on the shape of the program, so the number is
+    // double checked by actually running MONAD
+    let mut alu = Alu::new();
+    alu.run(&program, digits)
+        .map_err(|err| ParseError::new(err.pc() + 1, 1, format!("MONAD failed: {}", err)))?;
+    if alu.get(Register::Z) != 0 {
+        return Err(ParseError::unsolvable(format!("MONAD rejected {}", number)));
+    }
+
+    Ok(number)
+}
+
+pub fn solution1(text: &str) -> ParseResult<u64> {
+    solve(text, true)
+}
+
+pub fn solution2(text: &str) -> ParseResult<u64> {
+    solve(text, false)
+}
+
+solution!(24, "Arithmetic Logic Unit", parse);
+
+#[cfg(test)]
+mod twentyfour_tests {
+    use super::{parse, solution1, Alu, AluError, Register, BLOCK};
+    use crate::error::ParseError;
+
+    fn run(text: &str, input: &[i64]) -> Result<Alu, AluError> {
+        let mut alu = Alu::new();
+        alu.run(&parse(text).unwrap(), input.iter().copied())?;
+        Ok(alu)
+    }
+
+    #[test]
+    fn interpreter() {
+        assert_eq!(run("inp x\nmul x -1", &[7]).unwrap().get(Register::X), -7);
+
+        let triple = "inp z\ninp x\nmul z 3\neql z x";
+        assert_eq!(run(triple, &[3, 9]).unwrap().get(Register::Z), 1);
+        assert_eq!(run(triple, &[3, 8]).unwrap().get(Register::Z), 0);
+
+        let bits = run(
+            "inp w
+add z w
+mod z 2
+div w 2
+add y w
+mod y 2
+div w 2
+add x w
+mod x 2
+div w 2
+mod w 2",
+            &[13],
+        )
+        .unwrap();
+        assert_eq!(
+            [Register::W, Register::X, Register::Y, Register::Z].map(|r| bits.get(r)),
+            [1, 1, 0, 1]
+        );
+
+        assert_eq!(run("inp x\ninp y", &[1]), Err(AluError::MissingInput(1)));
+        assert_eq!(run("div x 0", &[]), Err(AluError::DivisionByZero(0)));
+        assert_eq!(
+            run("add x -1\nmod x 2", &[]),
+            Err(AluError::InvalidModulo(1))
+        );
+        assert_eq!(
+            run("inp x\nmul x 10\nmul x x\nmul x x", &[100_000]),
+            Err(AluError::Overflow(3))
+        );
+        assert_eq!(
+            run("add y -9223372036854775807\nadd y -2", &[]),
+            Err(AluError::Overflow(1))
+        );
+    }
+
+    fn block(constants: [i64; 3]) -> String {
+        let mut constants = constants.into_iter();
+        BLOCK
+            .iter()
+            .map(|line| {
+                if line.contains("{}") {
+                    line.replace("{}", &constants.next().unwrap().to_string())
+                } else {
+                    line.to_string()
+                }
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    #[test]
+    fn analysis() {
+        // A solvable MONAD is checked by the fixture in examples/2021/24
+        assert!(solution1("inp w\nadd z w").is_err());
+
+        // Every digit pushes, so `z` is never emptied
+        let pushes = vec![block([1, 12, 3]); 14].join("\n");
+        assert_eq!(
+            solution1(&pushes),
+            Err(ParseError::unsolvable("MONAD accepts no model number"))
+        );
+    }
+
+    #[test]
+    fn invalid() {
+        assert_eq!(
+            parse("inp w\nadd v 1"),
+            Err(ParseError::new(2, 5, "invalid register 'v'"))
+        );
+        assert_eq!(
+            parse("inp w\nsub x 1"),
+            Err(ParseError::new(2, 1, "invalid instruction 'sub'"))
+        );
+        assert_eq!(
+            parse("mul x"),
+            Err(ParseError::new(1, 1, "expected two operands"))
+        );
+    }
+}
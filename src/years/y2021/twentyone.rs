@@ -3,7 +3,8 @@ use std::{
     mem::take,
 };
 
-const INPUT: &str = include_str!("../../inputs/21");
+use crate::error::{parse_field, trim_blank_lines, ParseResult};
+use crate::solution::{solution, ParseError};
 
 trait ParsePlayer: Sized {
     fn create(name: &str) -> Option<Self>;
@@ -11,36 +12,30 @@ trait ParsePlayer: Sized {
 
 impl ParsePlayer for usize {
     fn create(id: &str) -> Option<Self> {
-        Some(id.parse().ok()?)
+        id.parse().ok()
     }
 }
 
-fn parse_player<P: ParsePlayer>(text: &str) -> Option<(P, usize)> {
-    let text = text.trim();
-    if text.starts_with("Player ") {
-        let text = &text[7..];
-        if let Some(pos) = text.find(' ') {
-            let player = <P as ParsePlayer>::create(&text[..pos])?;
-            let text = &text[pos..];
+fn parse_player<P: ParsePlayer>(i: usize, line: &str) -> ParseResult<(P, usize)> {
+    let invalid = || ParseError::new(i, 1, "expected 'Player <id> starting position: <n>'");
+    let text = line.trim().strip_prefix("Player ").ok_or_else(invalid)?;
+    let (id, position) = text
+        .split_once(" starting position: ")
+        .ok_or_else(invalid)?;
 
-            if text.starts_with(" starting position: ") {
-                let position = text[20..].parse::<usize>().ok()?;
+    let player = <P as ParsePlayer>::create(id)
+        .ok_or_else(|| ParseError::at(i, line, id, format!("invalid player '{}'", id)))?;
 
-                return Some((player, position));
-            }
-        }
-    }
-
-    None
+    Ok((player, parse_field(i, line, position)?))
 }
 
-fn parse<P: ParsePlayer + Ord>(text: &str) -> BTreeMap<P, usize> {
+fn parse<P: ParsePlayer + Ord>(text: &str) -> ParseResult<BTreeMap<P, usize>> {
     let mut players = BTreeMap::new();
-    for line in text.trim().lines() {
-        let (player, position) = parse_player(line).unwrap();
+    for (i, line) in trim_blank_lines(text).lines().enumerate() {
+        let (player, position) = parse_player(i + 1, line)?;
         players.insert(player, position);
     }
-    players
+    Ok(players)
 }
 
 trait Die {
@@ -115,7 +110,7 @@ where
         res
     }
 
-    pub fn step<D: Die, F: Field, Fun>(&mut self, die: &mut D, field: &mut F, cond: Fun) -> bool
+    pub fn step<D, F, Fun>(&mut self, die: &mut D, field: &mut F, cond: Fun) -> bool
     where
         D: Die,
         F: Field,
@@ -126,7 +121,7 @@ where
             field.step(&mut v.0, amount);
             v.1 += v.0;
 
-            if cond(&p, v.1) {
+            if cond(p, v.1) {
                 return true;
             }
         }
@@ -186,14 +181,14 @@ where
         self.state.rolled
     }
 
-    pub fn step_while<'a, Fun>(&'a mut self, cond: Fun) -> bool
+    pub fn step_while<Fun>(&mut self, cond: Fun) -> bool
     where
         Fun: Fn(&P, usize) -> bool,
     {
         self.state.step(&mut self.die, &mut self.field, cond)
     }
 
-    pub fn players<'a>(&'a self) -> &'a BTreeMap<P, (usize, usize)> {
+    pub fn players(&self) -> &BTreeMap<P, (usize, usize)> {
         &self.state.players
     }
 }
@@ -224,7 +219,7 @@ fn modulo(mut value: usize, min: usize, max: usize) -> usize {
         value -= q * range;
     }
 
-    return value;
+    value
 }
 
 fn solve_recursive(
@@ -266,51 +261,65 @@ fn solve_recursive(
         (total_p1_wins, total_p2_wins),
     );
 
-    return (total_p1_wins, total_p2_wins);
+    (total_p1_wins, total_p2_wins)
 }
 
-pub(crate) fn solution1(text: &str) -> usize {
-    let players: BTreeMap<usize, usize> = parse(text);
+pub fn solution1(text: &str) -> ParseResult<usize> {
+    let players: BTreeMap<usize, usize> = parse(text)?;
+    if players.is_empty() {
+        return Err(ParseError::new(1, 1, "expected at least 1 player, found 0"));
+    }
     let mut game = Game::new(players, DeterministicDie::new(100), MaxValueField::new(10));
 
     while !game.step_while(|_, i| i >= 1000) {}
-    let min = game.players().iter().map(|(_, i)| i.1).min();
-    min.unwrap() * game.rolled()
+    let min = game
+        .players()
+        .values()
+        .map(|i| i.1)
+        .min()
+        .unwrap_or_default();
+    Ok(min * game.rolled())
 }
 
-pub(crate) fn solution2(text: &str) -> usize {
-    let players: BTreeMap<usize, usize> = parse(text);
-    let mut it = players.into_iter();
+pub fn solution2(text: &str) -> ParseResult<usize> {
+    let players: BTreeMap<usize, usize> = parse(text)?;
+    let (p1, p2) = match players.values().collect::<Vec<_>>()[..] {
+        [&p1, &p2] => (p1, p2),
+        _ => {
+            return Err(ParseError::new(
+                players.len() + 1,
+                1,
+                format!("expected 2 players, found {}", players.len()),
+            ))
+        }
+    };
     let (wins_p1, wins_p2) = solve_recursive(
-        it.next().unwrap().1,
-        it.next().unwrap().1,
+        p1,
+        p2,
         0,
         0,
         &mut HashMap::new(),
         &generate_frequencies(1, 3, 3),
     );
-    wins_p1.max(wins_p2)
+    Ok(wins_p1.max(wins_p2))
 }
 
-pub fn solution() {
-    println!("Solution 1: {}", solution1(INPUT));
-    println!("Solution 2: {}", solution2(INPUT));
-}
+solution!(21, "Dirac Dice", parse::<usize>);
 
 #[cfg(test)]
 mod twentyone_tests {
     use super::{solution1, solution2};
-
-    const INPUT: &str = "Player 1 starting position: 4
-Player 2 starting position: 8";
-
-    #[test]
-    fn test1() {
-        assert_eq!(solution1(INPUT), 739785);
-    }
+    use crate::error::ParseError;
 
     #[test]
-    fn test2() {
-        assert_eq!(solution2(INPUT), 444356092776315);
+    fn players() {
+        assert_eq!(
+            solution1(""),
+            Err(ParseError::new(1, 1, "expected at least 1 player, found 0"))
+        );
+        assert_eq!(
+            solution2("Player 1 starting position: 4"),
+            Err(ParseError::new(2, 1, "expected 2 players, found 1"))
+        );
     }
 }
@@ -0,0 +1,352 @@
+use pathfinding::directed::astar::astar;
+
+use crate::error::ParseResult;
+use crate::solution::{solution, ParseError};
+
+const HALLWAY: usize = 11;
+const ROOMS: usize = 4;
+const MAX_DEPTH: usize = 4;
+/// Hallway positions right outside each room, where amphipods never stop
+const DOORS: [usize; ROOMS] = [2, 4, 6, 8];
+const ENERGY: [usize; ROOMS] = [1, 10, 100, 1000];
+/// Rows inserted in the middle of every room when the diagram is unfolded
+const FOLDED: [[u8; 2]; ROOMS] = [[4, 4], [3, 2], [2, 1], [1, 3]];
+
+const EMPTY: u8 = 0;
+
+/// Every amphipod is stored as its kind, 1 (Amber) to 4 (Desert), which is
+/// also the number of its destination room plus one. Room slots are listed
+/// from the hallway down, and only the first `depth` ones are used.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+struct Burrow {
+    hallway: [u8; HALLWAY],
+    rooms: [[u8; MAX_DEPTH]; ROOMS],
+    depth: usize,
+}
+
+fn distance(a: usize, b: usize) -> usize {
+    a.abs_diff(b)
+}
+
+impl Burrow {
+    fn room(&self, room: usize) -> &[u8] {
+        &self.rooms[room][..self.depth]
+    }
+
+    fn is_done(&self) -> bool {
+        (0..ROOMS).all(|room| self.room(room).iter().all(|&a| a as usize == room + 1))
+    }
+
+    /// Whether a room only holds amphipods that belong there, so that others
+    /// can move in and none of them has to move out.
+    fn is_settled(&self, room: usize) -> bool {
+        self.room(room)
+            .iter()
+            .all(|&a| a == EMPTY || a as usize == room + 1)
+    }
+
+    /// Whether the hallway is free between `from` (excluded) and `to`.
+    fn is_clear(&self, from: usize, to: usize) -> bool {
+        let range = if from < to {
+            from + 1..=to
+        } else {
+            to..=from - 1
+        };
+        self.hallway[range].iter().all(|&a| a == EMPTY)
+    }
+
+    /// The burrows reachable with a single move, along with its energy. An
+    /// amphipod either leaves its room for a hallway position, or goes from the
+    /// hallway to the deepest free slot of its own room.
+    fn moves(&self) -> Vec<(Burrow, usize)> {
+        let mut res = Vec::new();
+
+        for (from, &a) in self.hallway.iter().enumerate() {
+            if a == EMPTY {
+                continue;
+            }
+            let room = a as usize - 1;
+            let door = DOORS[room];
+            if !self.is_settled(room) || !self.is_clear(from, door) {
+                continue;
+            }
+
+            let Some(slot) = self.room(room).iter().rposition(|&a| a == EMPTY) else {
+                continue;
+            };
+            let mut next = *self;
+            next.hallway[from] = EMPTY;
+            next.rooms[room][slot] = a;
+            res.push((next, (distance(from, door) + slot + 1) * ENERGY[room]));
+        }
+
+        for (room, &door) in DOORS.iter().enumerate() {
+            if self.is_settled(room) {
+                continue;
+            }
+            let Some(slot) = self.room(room).iter().position(|&a| a != EMPTY) else {
+                continue;
+            };
+            let a = self.rooms[room][slot];
+
+            for to in (0..HALLWAY).filter(|to| !DOORS.contains(to)) {
+                if self.hallway[to] != EMPTY || !self.is_clear(door, to) {
+                    continue;
+                }
+
+                let mut next = *self;
+                next.rooms[room][slot] = EMPTY;
+                next.hallway[to] = a;
+                res.push((
+                    next,
+                    (slot + 1 + distance(door, to)) * ENERGY[a as usize - 1],
+                ));
+            }
+        }
+
+        res
+    }
+
+    /// A lower bound of the energy still needed: every amphipod walks straight
+    /// to the top slot of its room, ignoring the others.
+    fn heuristic(&self) -> usize {
+        let mut res = 0;
+
+        for (from, &a) in self.hallway.iter().enumerate() {
+            if a != EMPTY {
+                let room = a as usize - 1;
+                res += (distance(from, DOORS[room]) + 1) * ENERGY[room];
+            }
+        }
+
+        for (room, &door) in DOORS.iter().enumerate() {
+            // Amphipods already at the bottom of their own room never move
+            let settled = self
+                .room(room)
+                .iter()
+                .rev()
+                .take_while(|&&a| a as usize == room + 1)
+                .count();
+
+            for (slot, &a) in self.room(room)[..self.depth - settled].iter().enumerate() {
+                if a == EMPTY {
+                    continue;
+                }
+                let target = a as usize - 1;
+                // Leaving and entering the same room takes at least a step
+                // aside and back
+                let across = distance(door, DOORS[target]).max(2);
+                res += (slot + 1 + across + 1) * ENERGY[target];
+            }
+        }
+
+        res
+    }
+
+    fn unfold(&self) -> Self {
+        let mut res = *self;
+        res.depth = self.depth + 2;
+        for (room, folded) in FOLDED.iter().enumerate() {
+            res.rooms[room] = [EMPTY; MAX_DEPTH];
+            res.rooms[room][0] = self.rooms[room][0];
+            res.rooms[room][1..3].copy_from_slice(folded);
+            res.rooms[room][3..res.depth].copy_from_slice(&self.rooms[room][1..self.depth]);
+        }
+        res
+    }
+}
+
+fn parse_cell(i: usize, line: &str, column: usize) -> ParseResult<u8> {
+    match line.as_bytes().get(column) {
+        Some(b'.') => Ok(EMPTY),
+        Some(&c @ b'A'..=b'D') => Ok(c - b'A' + 1),
+        Some(&c) => Err(ParseError::new(
+            i,
+            column + 1,
+            format!("invalid cell '{}'", c as char),
+        )),
+        None => Err(ParseError::new(i, column + 1, "unexpected end of line")),
+    }
+}
+
+fn parse(text: &str) -> ParseResult<Burrow> {
+    let lines = text.lines().collect::<Vec<_>>();
+    let mut burrow = Burrow {
+        hallway: [EMPTY; HALLWAY],
+        rooms: [[EMPTY; MAX_DEPTH]; ROOMS],
+        depth: 0,
+    };
+
+    let hallway = lines
+        .get(1)
+        .ok_or_else(|| ParseError::new(2, 1, "missing hallway"))?;
+    for (pos, cell) in burrow.hallway.iter_mut().enumerate() {
+        *cell = parse_cell(2, hallway, pos + 1)?;
+    }
+
+    // Rooms go down until the bottom wall, which has no cells
+    for (i, line) in lines.iter().enumerate().skip(2) {
+        if DOORS
+            .iter()
+            .all(|&door| line.as_bytes().get(door + 1) == Some(&b'#'))
+        {
+            break;
+        }
+        if burrow.depth == MAX_DEPTH {
+            return Err(ParseError::new(
+                i + 1,
+                1,
+                format!("rooms are more than {} deep", MAX_DEPTH),
+            ));
+        }
+
+        for (room, &door) in DOORS.iter().enumerate() {
+            let cell = parse_cell(i + 1, line, door + 1)?;
+            // Moves out of a room only count the steps to its top
+            if cell != EMPTY && burrow.depth > 0 && burrow.rooms[room][burrow.depth - 1] == EMPTY {
+                return Err(ParseError::new(
+                    i + 1,
+                    door + 2,
+                    format!(
+                        "amphipod '{}' below an empty slot",
+                        (b'A' + cell - 1) as char
+                    ),
+                ));
+            }
+            burrow.rooms[room][burrow.depth] = cell;
+        }
+        burrow.depth += 1;
+    }
+
+    if burrow.depth == 0 {
+        return Err(ParseError::new(3, 1, "missing rooms"));
+    }
+
+    // Every room must end up full of its own kind
+    for kind in 1..=ROOMS as u8 {
+        let count = burrow
+            .hallway
+            .iter()
+            .chain(burrow.rooms.iter().flat_map(|room| &room[..burrow.depth]))
+            .filter(|&&a| a == kind)
+            .count();
+        if count != burrow.depth {
+            return Err(ParseError::new(
+                3,
+                1,
+                format!(
+                    "expected {} amphipods '{}', found {}",
+                    burrow.depth,
+                    (b'A' + kind - 1) as char,
+                    count
+                ),
+            ));
+        }
+    }
+
+    Ok(burrow)
+}
+
+/// The amphipods in the hallway may block each other for good, in which case
+/// the burrow is reported as unsolvable.
+fn solve(burrow: &Burrow) -> ParseResult<usize> {
+    astar(burrow, Burrow::moves, Burrow::heuristic, Burrow::is_done)
+        .map(|(_, energy)| energy)
+        .ok_or_else(|| ParseError::unsolvable("the amphipods cannot be organized"))
+}
+
+pub fn solution1(text: &str) -> ParseResult<usize> {
+    solve(&parse(text)?)
+}
+
+pub fn solution2(text: &str) -> ParseResult<usize> {
+    let burrow = parse(text)?;
+    if burrow.depth != 2 {
+        return Err(ParseError::new(
+            3,
+            1,
+            format!("expected rooms 2 deep, found {}", burrow.depth),
+        ));
+    }
+
+    solve(&burrow.unfold())
+}
+
+solution!(23, "Amphipod", parse);
+
+#[cfg(test)]
+mod twentythree_tests {
+    use super::{parse, solution1, solution2};
+    use crate::error::ParseError;
+
+    #[test]
+    fn unfolded() {
+        let folded = parse(
+            "#############
+#...........#
+###B#C#B#D###
+  #A#D#C#A#
+  #########",
+        )
+        .unwrap();
+        let unfolded = parse(
+            "#############
+#...........#
+###B#C#B#D###
+  #D#C#B#A#
+  #D#B#A#C#
+  #A#D#C#A#
+  #########",
+        )
+        .unwrap();
+
+        assert_eq!(folded.unfold(), unfolded);
+        assert_eq!(
+            solution1(
+                "#############
+#...........#
+###B#C#B#D###
+  #D#C#B#A#
+  #D#B#A#C#
+  #A#D#C#A#
+  #########"
+            ),
+            Ok(44169)
+        );
+        assert!(solution2(
+            "#############
+#...........#
+###B#C#B#D###
+  #D#C#B#A#
+  #D#B#A#C#
+  #A#D#C#A#
+  #########"
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn invalid() {
+        assert_eq!(
+            parse("#############\n#...........#\n###B#C#E#D###\n  #A#D#C#A#\n  #########"),
+            Err(ParseError::new(3, 8, "invalid cell 'E'"))
+        );
+        assert_eq!(
+            parse("#############\n#...........#\n  #########"),
+            Err(ParseError::new(3, 1, "missing rooms"))
+        );
+        assert_eq!(
+            parse("#############\n#A..........#\n###B#C#B#D###\n  #A#D#C#A#\n  #########"),
+            Err(ParseError::new(3, 1, "expected 2 amphipods 'A', found 3"))
+        );
+        assert_eq!(
+            parse("#############\n#B.........D#\n###.#B#C#.###\n  #A#D#C#A#\n  #########"),
+            Err(ParseError::new(4, 4, "amphipod 'A' below an empty slot"))
+        );
+        // A and D each block the other's way home
+        assert_eq!(
+            solution1("#############\n#...D...A...#\n###.#B#C#.###\n  #########"),
+            Err(ParseError::unsolvable("the amphipods cannot be organized"))
+        );
+    }
+}
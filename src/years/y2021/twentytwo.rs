@@ -4,7 +4,8 @@ use std::{
     ops::{Add, AddAssign, BitAnd, Sub, SubAssign},
 };
 
-const INPUT: &str = include_str!("../../inputs/22");
+use crate::error::{parse_lines, ParseResult};
+use crate::solution::{solution, ParseError};
 
 type N = isize;
 type Coord = (N, N, N);
@@ -22,7 +23,7 @@ fn range_bitand(start1: N, end1: N, start2: N, end2: N) -> Option<(N, N)> {
 }
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
-pub(crate) struct Cuboid {
+pub struct Cuboid {
     pub x1: N,
     pub y1: N,
     pub z1: N,
@@ -32,16 +33,14 @@ pub(crate) struct Cuboid {
 }
 
 impl Cuboid {
-    #[allow(dead_code)]
     pub fn overlaps(&self, other: &Self) -> bool {
         range_overlaps(self.x1, self.x2, other.x1, other.x2)
             && range_overlaps(self.y1, self.y2, other.y1, other.y2)
             && range_overlaps(self.z1, self.z2, other.z1, other.z2)
     }
 
-    #[allow(dead_code)]
     pub fn iter<'a>(&'a self) -> CuboidIter<'a> {
-        CuboidIter::new(&self)
+        CuboidIter::new(self)
     }
 
     pub fn len(&self) -> usize {
@@ -50,13 +49,12 @@ impl Cuboid {
             * ((self.z2 - self.z1) as usize + 1)
     }
 
-    #[allow(dead_code)]
     pub fn is_empty(&self) -> bool {
         self.len() == 0
     }
 }
 
-pub(crate) struct CuboidIter<'a> {
+pub struct CuboidIter<'a> {
     cuboid: &'a Cuboid,
     current: Option<Coord>,
 }
@@ -96,11 +94,11 @@ impl<'a> Iterator for CuboidIter<'a> {
     type Item = Coord;
 
     fn next(&mut self) -> Option<<Self as Iterator>::Item> {
-        iter_next(&self.cuboid, &mut self.current)
+        iter_next(self.cuboid, &mut self.current)
     }
 }
 
-pub(crate) struct CuboidIntoIter {
+pub struct CuboidIntoIter {
     cuboid: Cuboid,
     current: Option<Coord>,
 }
@@ -314,13 +312,12 @@ fn step_reduce_cuboids(cuboids: &mut Vec<Cuboid>) -> bool {
     false
 }
 
-#[allow(dead_code)]
 fn reduce_cuboids(cuboids: &mut Vec<Cuboid>) {
     while step_reduce_cuboids(cuboids) {}
 }
 
-#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
-pub(crate) struct SpaceSlice {
+#[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SpaceSlice {
     slices: Vec<Cuboid>,
 }
 
@@ -347,13 +344,11 @@ impl SpaceSlice {
         raw_len(self.cuboids())
     }
 
-    #[allow(dead_code)]
     #[inline]
     pub fn is_empty(&self) -> bool {
         raw_is_empty(self.cuboids())
     }
 
-    #[allow(dead_code)]
     #[inline]
     pub fn reduce(&mut self) {
         reduce_cuboids(&mut self.slices)
@@ -393,11 +388,11 @@ fn raw_add(source: &mut Vec<Cuboid>, mut add: Vec<Cuboid>) {
     }
 }
 
-fn raw_len(slices: &Vec<Cuboid>) -> usize {
+fn raw_len(slices: &[Cuboid]) -> usize {
     slices.iter().map(Cuboid::len).sum()
 }
 
-fn raw_is_empty(slices: &Vec<Cuboid>) -> bool {
+fn raw_is_empty(slices: &[Cuboid]) -> bool {
     raw_len(slices) == 0
 }
 
@@ -576,17 +571,15 @@ impl Sub<&SpaceSlice> for &Cuboid {
     }
 }
 
-impl Into<SpaceSlice> for Cuboid {
-    fn into(self) -> SpaceSlice {
-        SpaceSlice { slices: vec![self] }
+impl From<Cuboid> for SpaceSlice {
+    fn from(val: Cuboid) -> Self {
+        SpaceSlice { slices: vec![val] }
     }
 }
 
-impl Into<SpaceSlice> for &Cuboid {
-    fn into(self) -> SpaceSlice {
-        SpaceSlice {
-            slices: vec![*self],
-        }
+impl From<&Cuboid> for SpaceSlice {
+    fn from(val: &Cuboid) -> Self {
+        SpaceSlice { slices: vec![*val] }
     }
 }
 
@@ -604,10 +597,7 @@ impl Operation {
         }
     }
     pub fn is_on(&self) -> bool {
-        match self {
-            Self::On(_) => true,
-            _ => false,
-        }
+        matches!(self, Self::On(_))
     }
 
     pub fn apply(&self, slice: &mut SpaceSlice) {
@@ -619,42 +609,48 @@ impl Operation {
     }
 }
 
-fn parse_line(text: &str) -> Operation {
+fn parse_line(i: usize, text: &str) -> ParseResult<Operation> {
     let text = text.trim();
 
-    if let Ok((x1, x2, y1, y2, z1, z2)) =
-        scan_fmt!(text, "on x={}..{},y={}..{},z={}..{}", N, N, N, N, N, N)
-    {
-        Operation::On(Cuboid {
-            x1,
-            y1,
-            z1,
-            x2,
-            y2,
-            z2,
-        })
-    } else if let Ok((x1, x2, y1, y2, z1, z2)) =
-        scan_fmt!(text, "off x={}..{},y={}..{},z={}..{}", N, N, N, N, N, N)
-    {
-        Operation::Off(Cuboid {
-            x1,
-            y1,
-            z1,
-            x2,
-            y2,
-            z2,
-        })
-    } else {
-        unreachable!()
-    }
+    Ok(
+        if let Ok((x1, x2, y1, y2, z1, z2)) =
+            scan_fmt!(text, "on x={}..{},y={}..{},z={}..{}", N, N, N, N, N, N)
+        {
+            Operation::On(Cuboid {
+                x1,
+                y1,
+                z1,
+                x2,
+                y2,
+                z2,
+            })
+        } else if let Ok((x1, x2, y1, y2, z1, z2)) =
+            scan_fmt!(text, "off x={}..{},y={}..{},z={}..{}", N, N, N, N, N, N)
+        {
+            Operation::Off(Cuboid {
+                x1,
+                y1,
+                z1,
+                x2,
+                y2,
+                z2,
+            })
+        } else {
+            return Err(ParseError::new(
+                i,
+                1,
+                "expected '<on|off> x=<n>..<n>,y=<n>..<n>,z=<n>..<n>'",
+            ));
+        },
+    )
 }
 
-fn parse(text: &str) -> Vec<Operation> {
-    text.trim().lines().map(|l| parse_line(l)).collect()
+fn parse(text: &str) -> ParseResult<Vec<Operation>> {
+    parse_lines(text, parse_line)
 }
 
-pub(crate) fn solve(text: &str, limits: Option<Cuboid>) -> usize {
-    let mut operations: Vec<Operation> = parse(text);
+pub fn solve(text: &str, limits: Option<Cuboid>) -> ParseResult<usize> {
+    let mut operations: Vec<Operation> = parse(text)?;
     if let Some(limits) = limits {
         operations = operations
             .into_iter()
@@ -669,16 +665,16 @@ pub(crate) fn solve(text: &str, limits: Option<Cuboid>) -> usize {
             })
             .collect();
     }
-    operations
+    Ok(operations
         .into_iter()
         .fold(SpaceSlice::new(), |mut acc, op| {
             op.apply(&mut acc);
             acc
         })
-        .len()
+        .len())
 }
 
-pub(crate) fn solution1(text: &str) -> usize {
+pub fn solution1(text: &str) -> ParseResult<usize> {
     solve(
         text,
         Some(Cuboid {
@@ -692,120 +688,8 @@ pub(crate) fn solution1(text: &str) -> usize {
     )
 }
 
-pub(crate) fn solution2(text: &str) -> usize {
+pub fn solution2(text: &str) -> ParseResult<usize> {
     solve(text, None)
 }
 
-pub fn solution() {
-    println!("Solution 1: {}", solution1(INPUT));
-    println!("Solution 2: {}", solution2(INPUT));
-}
-
-#[cfg(test)]
-mod twentytwo_tests {
-    use super::{solution1, solution2};
-
-    const INPUT1: &str = "on x=10..12,y=10..12,z=10..12
-on x=11..13,y=11..13,z=11..13
-off x=9..11,y=9..11,z=9..11
-on x=10..10,y=10..10,z=10..10";
-
-    const INPUT2: &str = "on x=-20..26,y=-36..17,z=-47..7
-on x=-20..33,y=-21..23,z=-26..28
-on x=-22..28,y=-29..23,z=-38..16
-on x=-46..7,y=-6..46,z=-50..-1
-on x=-49..1,y=-3..46,z=-24..28
-on x=2..47,y=-22..22,z=-23..27
-on x=-27..23,y=-28..26,z=-21..29
-on x=-39..5,y=-6..47,z=-3..44
-on x=-30..21,y=-8..43,z=-13..34
-on x=-22..26,y=-27..20,z=-29..19
-off x=-48..-32,y=26..41,z=-47..-37
-on x=-12..35,y=6..50,z=-50..-2
-off x=-48..-32,y=-32..-16,z=-15..-5
-on x=-18..26,y=-33..15,z=-7..46
-off x=-40..-22,y=-38..-28,z=23..41
-on x=-16..35,y=-41..10,z=-47..6
-off x=-32..-23,y=11..30,z=-14..3
-on x=-49..-5,y=-3..45,z=-29..18
-off x=18..30,y=-20..-8,z=-3..13
-on x=-41..9,y=-7..43,z=-33..15
-on x=-54112..-39298,y=-85059..-49293,z=-27449..7877
-on x=967..23432,y=45373..81175,z=27513..53682";
-
-    const INPUT3: &str = "on x=-5..47,y=-31..22,z=-19..33
-on x=-44..5,y=-27..21,z=-14..35
-on x=-49..-1,y=-11..42,z=-10..38
-on x=-20..34,y=-40..6,z=-44..1
-off x=26..39,y=40..50,z=-2..11
-on x=-41..5,y=-41..6,z=-36..8
-off x=-43..-33,y=-45..-28,z=7..25
-on x=-33..15,y=-32..19,z=-34..11
-off x=35..47,y=-46..-34,z=-11..5
-on x=-14..36,y=-6..44,z=-16..29
-on x=-57795..-6158,y=29564..72030,z=20435..90618
-on x=36731..105352,y=-21140..28532,z=16094..90401
-on x=30999..107136,y=-53464..15513,z=8553..71215
-on x=13528..83982,y=-99403..-27377,z=-24141..23996
-on x=-72682..-12347,y=18159..111354,z=7391..80950
-on x=-1060..80757,y=-65301..-20884,z=-103788..-16709
-on x=-83015..-9461,y=-72160..-8347,z=-81239..-26856
-on x=-52752..22273,y=-49450..9096,z=54442..119054
-on x=-29982..40483,y=-108474..-28371,z=-24328..38471
-on x=-4958..62750,y=40422..118853,z=-7672..65583
-on x=55694..108686,y=-43367..46958,z=-26781..48729
-on x=-98497..-18186,y=-63569..3412,z=1232..88485
-on x=-726..56291,y=-62629..13224,z=18033..85226
-on x=-110886..-34664,y=-81338..-8658,z=8914..63723
-on x=-55829..24974,y=-16897..54165,z=-121762..-28058
-on x=-65152..-11147,y=22489..91432,z=-58782..1780
-on x=-120100..-32970,y=-46592..27473,z=-11695..61039
-on x=-18631..37533,y=-124565..-50804,z=-35667..28308
-on x=-57817..18248,y=49321..117703,z=5745..55881
-on x=14781..98692,y=-1341..70827,z=15753..70151
-on x=-34419..55919,y=-19626..40991,z=39015..114138
-on x=-60785..11593,y=-56135..2999,z=-95368..-26915
-on x=-32178..58085,y=17647..101866,z=-91405..-8878
-on x=-53655..12091,y=50097..105568,z=-75335..-4862
-on x=-111166..-40997,y=-71714..2688,z=5609..50954
-on x=-16602..70118,y=-98693..-44401,z=5197..76897
-on x=16383..101554,y=4615..83635,z=-44907..18747
-off x=-95822..-15171,y=-19987..48940,z=10804..104439
-on x=-89813..-14614,y=16069..88491,z=-3297..45228
-on x=41075..99376,y=-20427..49978,z=-52012..13762
-on x=-21330..50085,y=-17944..62733,z=-112280..-30197
-on x=-16478..35915,y=36008..118594,z=-7885..47086
-off x=-98156..-27851,y=-49952..43171,z=-99005..-8456
-off x=2032..69770,y=-71013..4824,z=7471..94418
-on x=43670..120875,y=-42068..12382,z=-24787..38892
-off x=37514..111226,y=-45862..25743,z=-16714..54663
-off x=25699..97951,y=-30668..59918,z=-15349..69697
-off x=-44271..17935,y=-9516..60759,z=49131..112598
-on x=-61695..-5813,y=40978..94975,z=8655..80240
-off x=-101086..-9439,y=-7088..67543,z=33935..83858
-off x=18020..114017,y=-48931..32606,z=21474..89843
-off x=-77139..10506,y=-89994..-18797,z=-80..59318
-off x=8476..79288,y=-75520..11602,z=-96624..-24783
-on x=-47488..-1262,y=24338..100707,z=16292..72967
-off x=-84341..13987,y=2429..92914,z=-90671..-1318
-off x=-37810..49457,y=-71013..-7894,z=-105357..-13188
-off x=-27365..46395,y=31009..98017,z=15428..76570
-off x=-70369..-16548,y=22648..78696,z=-1892..86821
-on x=-53470..21291,y=-120233..-33476,z=-44150..38147
-off x=-93533..-4276,y=-16170..68771,z=-104985..-24507";
-
-    #[test]
-    fn test1() {
-        assert_eq!(solution1(INPUT1), 39);
-    }
-
-    #[test]
-    fn test2() {
-        assert_eq!(solution1(INPUT2), 590784);
-    }
-
-    #[test]
-    fn test3() {
-        assert_eq!(solution2(INPUT3), 2758514936282235);
-    }
-}
+solution!(22, "Reactor Reboot", parse);
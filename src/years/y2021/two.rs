@@ -2,7 +2,9 @@
 
 use std::{fmt::Debug, str::FromStr};
 
-const INPUT: &str = include_str!("../../inputs/2");
+use crate::error::{parse_lines, ParseResult};
+use crate::solution::{solution, ParseError};
+
 const INVALID: &str = "Invalid command";
 
 #[derive(Copy, Clone, Default, Debug)]
@@ -47,23 +49,30 @@ impl FromStr for Command {
     type Err = InvalidCommand;
 
     fn from_str(line: &str) -> Result<Self, <Self as FromStr>::Err> {
-        if line.starts_with("up ") {
-            Ok(Command::Up(line[3..].parse().or(Err(InvalidCommand))?))
-        } else if line.starts_with("down ") {
-            Ok(Command::Down(line[5..].parse().or(Err(InvalidCommand))?))
-        } else if line.starts_with("forward ") {
-            Ok(Command::Forward(line[8..].parse().or(Err(InvalidCommand))?))
+        if let Some(amount) = line.strip_prefix("up ") {
+            Ok(Command::Up(amount.parse().or(Err(InvalidCommand))?))
+        } else if let Some(amount) = line.strip_prefix("down ") {
+            Ok(Command::Down(amount.parse().or(Err(InvalidCommand))?))
+        } else if let Some(amount) = line.strip_prefix("forward ") {
+            Ok(Command::Forward(amount.parse().or(Err(InvalidCommand))?))
         } else {
             Err(InvalidCommand)
         }
     }
 }
 
-fn solve(text: &str, apply_fn: fn(State, Command) -> State) -> isize {
-    text.lines()
-        .map(|line| <Command as FromStr>::from_str(line).unwrap())
+fn parse(text: &str) -> ParseResult<Vec<Command>> {
+    parse_lines(text, |i, line| {
+        <Command as FromStr>::from_str(line)
+            .map_err(|err| ParseError::new(i, 1, format!("{} '{}'", err, line)))
+    })
+}
+
+fn solve(text: &str, apply_fn: fn(State, Command) -> State) -> ParseResult<isize> {
+    Ok(parse(text)?
+        .into_iter()
         .fold(State::new(), apply_fn)
-        .result()
+        .result())
 }
 
 fn apply1(mut state: State, cmd: Command) -> State {
@@ -99,37 +108,12 @@ fn apply2(mut state: State, cmd: Command) -> State {
     state
 }
 
-pub(crate) fn solution1(text: &str) -> isize {
+pub fn solution1(text: &str) -> ParseResult<isize> {
     solve(text, apply1)
 }
 
-pub(crate) fn solution2(text: &str) -> isize {
+pub fn solution2(text: &str) -> ParseResult<isize> {
     solve(text, apply2)
 }
 
-pub fn solution() -> () {
-    println!("Solution 1: {}", solution1(INPUT));
-    println!("Solution 2: {}", solution2(INPUT));
-}
-
-#[cfg(test)]
-mod two_tests {
-    use crate::days::two::{solution1, solution2};
-
-    const TEST: &str = "forward 5
-down 5
-forward 8
-up 3
-down 8
-forward 2";
-
-    #[test]
-    fn test1() -> () {
-        assert_eq!(solution1(TEST), 150);
-    }
-
-    #[test]
-    fn test2() -> () {
-        assert_eq!(solution2(TEST), 900);
-    }
-}
+solution!(2, "Dive!", parse);